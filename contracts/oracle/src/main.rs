@@ -8,7 +8,7 @@ use tokio::time::sleep;
 use serde::{Deserialize, Serialize};
 
 // Bitcoin RPC client
-use bitcoincore_rpc::{Auth, Client as BitcoinClient, RpcApi};
+use bitcoincore_rpc::{json::BlockStatsFields, Auth, Client as BitcoinClient, RpcApi};
 
 // Arch SDK for submitting transactions
 use arch_program::{
@@ -70,6 +70,13 @@ pub struct AddressMapping {
     pub arch_pubkey: Pubkey,
 }
 
+/// Fee pressure of one confirmed block (from `getblockstats`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlockFeeStats {
+    pub median_fee_rate: u64, // sat/vB
+    pub weight: u64,          // Block weight (max 4M)
+}
+
 #[derive(Debug, Clone)]
 pub struct SyncState {
     pub last_synced_block: u64,
@@ -77,6 +84,42 @@ pub struct SyncState {
     pub total_transfers_processed: u64,
 }
 
+// ============================================================================
+// MEMPOOL WEATHER
+// ============================================================================
+
+// Weather values (Weather enum order in the Satsgotchi program)
+pub const WEATHER_QUIET: u8 = 0;
+pub const WEATHER_NORMAL: u8 = 1;
+pub const WEATHER_STORM: u8 = 2;
+
+// Classification over the most recent confirmed blocks
+pub const WEATHER_WINDOW_BLOCKS: u64 = 6;
+pub const STORM_FEE_RATE: u64 = 50;        // sat/vB, median
+pub const QUIET_FEE_RATE: u64 = 2;         // sat/vB, median
+pub const QUIET_FULLNESS_PCT: u64 = 50;    // Blocks under half full are quiet
+pub const MAX_BLOCK_WEIGHT: u64 = 4_000_000;
+
+/// Classify recent blocks: fee spikes are storms, cheap or half-empty
+/// blocks are quiet
+pub fn classify_weather(blocks: &[BlockFeeStats]) -> u8 {
+    if blocks.is_empty() {
+        return WEATHER_NORMAL;
+    }
+    
+    let count = blocks.len() as u64;
+    let fee_rate = blocks.iter().map(|b| b.median_fee_rate).sum::<u64>() / count;
+    let fullness_pct = blocks.iter().map(|b| b.weight).sum::<u64>() * 100 / (count * MAX_BLOCK_WEIGHT);
+    
+    if fee_rate >= STORM_FEE_RATE {
+        WEATHER_STORM
+    } else if fee_rate <= QUIET_FEE_RATE || fullness_pct < QUIET_FULLNESS_PCT {
+        WEATHER_QUIET
+    } else {
+        WEATHER_NORMAL
+    }
+}
+
 // ============================================================================
 // ORACLE CONFIGURATION
 // ============================================================================
@@ -131,6 +174,7 @@ pub struct Oracle {
     http_client: reqwest::Client,
     db: SqlConnection,
     address_registry: HashMap<String, Pubkey>,
+    last_weather_block: u64,
 }
 
 impl Oracle {
@@ -165,6 +209,7 @@ impl Oracle {
             http_client,
             db,
             address_registry,
+            last_weather_block: 0,
         })
     }
     
@@ -343,9 +388,53 @@ impl Oracle {
         // Update sync state
         self.update_sync_state(confirmed_block, processed)?;
         
+        // Weather is best effort; a missed reading just goes stale on-chain
+        if let Err(e) = self.publish_weather(confirmed_block).await {
+            eprintln!("⚠️  Failed to publish weather: {}", e);
+        }
+        
         Ok(processed)
     }
     
+    /// Publish the mempool weather for the latest confirmed block
+    async fn publish_weather(&mut self, confirmed_block: u64) -> Result<()> {
+        if confirmed_block <= self.last_weather_block {
+            return Ok(());
+        }
+        
+        let weather = self.read_weather(confirmed_block)?;
+        let instruction_data = self.create_publish_weather_instruction(weather, confirmed_block);
+        self.submit_arch_transaction(instruction_data).await?;
+        
+        self.last_weather_block = confirmed_block;
+        println!("🌦️  Weather at block {}: {}", confirmed_block, weather);
+        
+        Ok(())
+    }
+    
+    /// Classify the weather from the blocks ending at `tip`
+    fn read_weather(&self, tip: u64) -> Result<u8> {
+        let first = tip.saturating_sub(WEATHER_WINDOW_BLOCKS - 1);
+        let blocks = (first..=tip)
+            .map(|height| self.fetch_block_fee_stats(height))
+            .collect::<Result<Vec<_>>>()?;
+        
+        Ok(classify_weather(&blocks))
+    }
+    
+    /// Median fee rate and weight of one block via `getblockstats`
+    fn fetch_block_fee_stats(&self, height: u64) -> Result<BlockFeeStats> {
+        let stats = self.bitcoin_client.get_block_stats_fields(
+            height,
+            &[BlockStatsFields::FeeRatePercentiles, BlockStatsFields::TotalWeight],
+        )?;
+        
+        Ok(BlockFeeStats {
+            median_fee_rate: stats.fee_rate_percentiles.map_or(0, |p| p.fr_50th.to_sat()),
+            weight: stats.total_weight.unwrap_or(0) as u64,
+        })
+    }
+    
     /// Fetch Ordinals transfers from API
     async fn fetch_ordinals_transfers(
         &self,
@@ -408,6 +497,17 @@ impl Oracle {
         Ok(data)
     }
     
+    /// Create PublishWeather instruction bytes
+    fn create_publish_weather_instruction(&self, weather: u8, observed_block: u64) -> Vec<u8> {
+        // PublishWeather is index 22
+        let instruction_index: u8 = 22;
+        
+        let mut data = vec![instruction_index, weather];
+        data.extend_from_slice(&observed_block.to_le_bytes());
+        
+        data
+    }
+    
     /// Submit transaction to Arch Network
     async fn submit_arch_transaction(&self, instruction_data: Vec<u8>) -> Result<String> {
         // In production, this would:
@@ -601,4 +701,51 @@ mod tests {
         
         assert_eq!(count, 1);
     }
+
+    /// Local bitcoind stand-in answering `getblockstats` from a fee table
+    fn fake_bitcoind(blocks: HashMap<u64, BlockFeeStats>) -> mockito::ServerGuard {
+        let mut server = mockito::Server::new();
+        server
+            .mock("POST", "/")
+            .with_body_from_request(move |request| {
+                let call: serde_json::Value = serde_json::from_slice(request.body().unwrap()).unwrap();
+                let stats = blocks[&call["params"][0].as_u64().unwrap()];
+                serde_json::json!({
+                    "id": call["id"],
+                    "error": null,
+                    "result": {
+                        "feerate_percentiles": [1, 1, stats.median_fee_rate, 100, 200],
+                        "total_weight": stats.weight,
+                    },
+                })
+                .to_string()
+                .into()
+            })
+            .create();
+        server
+    }
+
+    #[test]
+    fn test_weather_from_bitcoind() {
+        let stats = |median_fee_rate, weight| BlockFeeStats { median_fee_rate, weight };
+        let mut blocks: HashMap<u64, BlockFeeStats> = (95..=100).map(|h| (h, stats(20, 3_990_000))).collect();
+        blocks.extend((101..=106).map(|h| (h, stats(120, 3_999_000))));
+        blocks.extend((107..=112).map(|h| (h, stats(1, 900_000))));
+        let server = fake_bitcoind(blocks);
+
+        let oracle = Oracle::new(OracleConfig {
+            bitcoin_rpc_url: server.url(),
+            db_path: ":memory:".to_string(),
+            ..OracleConfig::default()
+        })
+        .unwrap();
+
+        assert_eq!(oracle.read_weather(100).unwrap(), WEATHER_NORMAL);
+        assert_eq!(oracle.read_weather(106).unwrap(), WEATHER_STORM);
+        assert_eq!(oracle.read_weather(112).unwrap(), WEATHER_QUIET);
+        assert_eq!(
+            oracle.create_publish_weather_instruction(WEATHER_STORM, 106),
+            [&[22, WEATHER_STORM][..], &106u64.to_le_bytes()].concat()
+        );
+    }
 }
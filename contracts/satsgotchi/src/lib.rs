@@ -8,15 +8,19 @@ use std::collections::HashMap;
 // Arch SDK imports (from real Arch Network SDK)
 use arch_program::{
     account::AccountInfo,
-    entrypoint,
     helper::add_state_transition,
+    instruction::{AccountMeta, Instruction},
     msg,
+    program::invoke,
     program_error::ProgramError,
     pubkey::Pubkey,
     utxo::UtxoMeta,
     system_instruction::SystemInstruction,
 };
 
+// $GOTCHI token state (circulating supply for earnings) and its instructions (burns)
+use gotchi_token::{calculate_dynamic_burn, AccountBalance, TokenInstruction, TokenState};
+
 // Re-export for convenience
 pub use arch_program;
 
@@ -24,7 +28,7 @@ pub use arch_program;
 // STATE DEFINITIONS
 // ============================================================================
 
-#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq, PartialOrd)]
 pub enum Level {
     Egg,
     Baby,
@@ -41,19 +45,282 @@ pub enum Status {
     Dead,
 }
 
-#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
 pub struct Traits {
     pub rarity: u8,           // 0=Common, 1=Uncommon, 2=Rare, 3=Epic, 4=Legendary
-    pub color_shift: u8,      // 0-360 for hue rotation
-    pub pet_type: u8,         // Different species
+    pub color_shift: u16,     // 0-360 for hue rotation
+    pub pet_type: u8,         // Index into SPECIES
     pub accessories: Vec<u8>, // List of equipped accessories
 }
 
+// Care actions a caretaker can be allowed to perform (bitmask)
+pub const CARE_FEED: u8 = 1 << 0;
+pub const CARE_PLAY: u8 = 1 << 1;
+pub const CARE_CLEAN: u8 = 1 << 2;
+pub const CARE_MEDICINE: u8 = 1 << 3;
+pub const CARE_ALL: u8 = CARE_FEED | CARE_PLAY | CARE_CLEAN | CARE_MEDICINE;
+
+pub const MAX_CARETAKERS: usize = 4;
+
+// Most pet accounts a single BatchUpdateState may tick
+pub const MAX_BATCH_PETS: usize = 32;
+
+// Keeper incentives for cranking UpdateState
+pub const KEEPER_REWARD: u64 = 100_000_000;        // 0.1 $GOTCHI per rewarded update
+pub const KEEPER_MIN_BLOCKS: u64 = 36;             // Pet must advance >= 6 hours (and pays at most this often)
+pub const MAX_KEEPER_REWARDS_PER_BLOCK: u8 = 32;   // Rewarded updates per keeper per block
+
+// Vacation mode: at most 28 days (144 blocks/day) of frozen time per lifetime
+pub const MAX_LIFETIME_FROZEN_BLOCKS: u64 = 4_032;
+
+// Care action cooldowns (blocks, ~10 min each)
+pub const FEED_COOLDOWN_BLOCKS: u64 = 12;      // 2 hours
+pub const PLAY_COOLDOWN_BLOCKS: u64 = 6;       // 1 hour
+pub const CLEAN_COOLDOWN_BLOCKS: u64 = 6;      // 1 hour
+pub const MEDICINE_COOLDOWN_BLOCKS: u64 = 72;  // 12 hours
+
+// Repeats of the same action inside this window get halved effects
+pub const CARE_WINDOW_BLOCKS: u64 = 144;       // 1 day
+
+// Achievement badges (bitmask, see ACHIEVEMENTS)
+pub const BADGE_FIRST_EVOLUTION: u32 = 1 << 0;  // Grew out of Baby
+pub const BADGE_PERFECT_MONTH: u32 = 1 << 1;    // 30 perfect care days
+pub const BADGE_FLAWLESS_SENIOR: u32 = 1 << 2;  // Reached Senior with zero care mistakes
+pub const BADGE_CENTENARIAN: u32 = 1 << 3;      // 100 days alive
+pub const BADGE_ASCENDED: u32 = 1 << 4;         // Reached Ascension
+pub const BADGE_BEST_FRIENDS: u32 = 1 << 5;     // Top friendship level with another pet
+
+// Daily quests (one quest day = 144 blocks)
+pub const QUEST_DAY_BLOCKS: u64 = 144;
+pub const QUESTS_PER_DAY: usize = 3;
+
+// Ended care streaks kept per pet for leaderboards
+pub const MAX_STREAK_HISTORY: usize = 8;
+
+// Bitcoin event calendar
+pub const DIFFICULTY_ADJUSTMENT_INTERVAL: u64 = 2_016;
+pub const HALVING_INTERVAL: u64 = 210_000;
+pub const HALVING_ACCESSORY_BASE: u8 = 200; // Accessory id = base + halving epoch
+
+// Earning rates are parts per billion of circulating supply per hour
+pub const REWARD_RATE_SCALE: u64 = 1_000_000_000;
+pub const BLOCKS_PER_HOUR: u64 = 6;
+
+// Game config account (PDA) and its governance
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const MIN_CONFIG_TIMELOCK_BLOCKS: u64 = 144; // Parameter changes wait >= 1 day
+
+// Mempool weather account (PDA) published by the oracle
+pub const WEATHER_SEED: &[u8] = b"weather";
+pub const WEATHER_MAX_AGE_BLOCKS: u64 = 6; // Older readings count as Normal
+pub const MAX_WEATHER_READINGS: usize = 144; // About a day of readings kept for ticks to catch up on
+
+// Social visits: one Friendship account (PDA of FRIENDSHIP_SEED + both pets,
+// lower key first) per pair of pets
+pub const FRIENDSHIP_SEED: &[u8] = b"friendship";
+pub const FRIENDSHIP_LEVEL_VISITS: [u32; 5] = [1, 3, 7, 14, 30]; // Visits to reach levels 1-5
+pub const MAX_FRIENDSHIP_LEVEL: u8 = 5;
+pub const MAX_VISIT_LOG: usize = 8;
+
+// Pet names: one NameRecord account (PDA of NAME_SEED + lowercased name) per name
+pub const NAME_SEED: &[u8] = b"name";
+pub const MIN_NAME_LEN: usize = 3;
+pub const MAX_NAME_LEN: usize = 16; // Letters, digits, '-', '_' and single inner spaces
+
+// Households: one Household account (PDA of HOUSEHOLD_SEED + owner) per owner
+pub const HOUSEHOLD_SEED: &[u8] = b"household";
+
+/// A pet sitter the owner has delegated care actions to
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct Caretaker {
+    pub pubkey: Pubkey,
+    pub expires_block: u64,   // Delegation ends at this Bitcoin block
+    pub allowed_actions: u8,  // CARE_* bitmask
+}
+
+/// When a care action was last used and how often in its current window
+#[derive(Debug, Clone, Default, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct ActionCooldown {
+    pub last_block: u64,
+    pub window_start_block: u64,
+    pub uses_in_window: u8,
+}
+
+/// Anti-spam bookkeeping, one slot per care action
+#[derive(Debug, Clone, Default, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct CareCooldowns {
+    pub feed: ActionCooldown,
+    pub play: ActionCooldown,
+    pub clean: ActionCooldown,
+    pub medicine: ActionCooldown,
+}
+
+/// Rewards a previous owner earned before selling the pet, claimable by them
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct SettledRewards {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+/// Something to do today, beyond keeping the pet alive
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Quest {
+    Feed { times: u8 },
+    Play { times: u8 },
+    KeepHungerBelow { max: u8 },   // All day; judged when the day ends
+    CleanWithin { blocks: u64 },   // Clean within this many blocks of a poop
+}
+
+/// Quests the daily set is drawn from
+pub const QUEST_POOL: [Quest; 8] = [
+    Quest::Feed { times: 2 },
+    Quest::Feed { times: 3 },
+    Quest::Play { times: 3 },
+    Quest::Play { times: 4 },
+    Quest::KeepHungerBelow { max: 30 },
+    Quest::KeepHungerBelow { max: 50 },
+    Quest::CleanWithin { blocks: 6 },
+    Quest::CleanWithin { blocks: 12 },
+];
+
+/// Today's quest progress for a pet
+#[derive(Debug, Clone, Default, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct QuestProgress {
+    pub day: u64,               // current_block / QUEST_DAY_BLOCKS
+    pub feeds: u8,
+    pub plays: u8,
+    pub fastest_clean: u64,     // Fewest blocks a poop sat before cleaning (u64::MAX = none)
+    pub max_hunger: u8,
+    pub completed: u8,          // Bitmask of quest slots already paid
+}
+
+/// Something that happened to a pet
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq)]
+pub enum PetEventKind {
+    Feed,
+    Snack,
+    Play,
+    Clean,
+    Medicine,
+    Evolve,
+    Freeze,
+    Transfer,
+    CareMistake,
+    FellSick,
+    Died,
+    Visit,
+}
+
+/// One history entry: the meter changes it caused and the care mistake
+/// total afterwards. Events from the same update share one set of deltas
+/// (carried by the first of them).
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct PetEvent {
+    pub kind: PetEventKind,
+    pub block: u64,
+    pub health_delta: i8,
+    pub happiness_delta: i8,
+    pub hunger_delta: i8,
+    pub care_mistakes: u8,
+}
+
+/// The last `HISTORY_LEN` events; once full, `next` is the oldest slot
+#[derive(Debug, Clone, Default, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct EventHistory {
+    pub events: Vec<PetEvent>,
+    pub next: u8,
+}
+
+/// A care streak that has ended, credited to whoever owned the pet
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct StreakRecord {
+    pub owner: Pubkey,
+    pub days: u16,
+    pub ended_block: u64,
+}
+
+/// A badge and the permanent `care_multiplier` bonus it grants
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Achievement {
+    pub badge: u32,
+    pub name: &'static str,
+    pub multiplier_bonus: u16,
+}
+
+/// Every achievement the game knows about
+pub const ACHIEVEMENTS: [Achievement; 6] = [
+    Achievement { badge: BADGE_FIRST_EVOLUTION, name: "First Steps", multiplier_bonus: 0 },
+    Achievement { badge: BADGE_PERFECT_MONTH, name: "Perfect Month", multiplier_bonus: 5 },
+    Achievement { badge: BADGE_FLAWLESS_SENIOR, name: "Flawless Elder", multiplier_bonus: 10 },
+    Achievement { badge: BADGE_CENTENARIAN, name: "Centenarian", multiplier_bonus: 5 },
+    Achievement { badge: BADGE_ASCENDED, name: "Ascended", multiplier_bonus: 0 },
+    Achievement { badge: BADGE_BEST_FRIENDS, name: "Best Friends", multiplier_bonus: 5 },
+];
+
+// Trait ranges checked at Initialize
+pub const MAX_RARITY: u8 = 4;
+pub const MAX_COLOR_SHIFT: u16 = 360;
+
+// Body condition: weight from meals and snacks, fitness from play
+pub const BIRTH_WEIGHT: u8 = 10;
+pub const IDEAL_WEIGHT_MIN: u8 = 5;
+pub const IDEAL_WEIGHT_MAX: u8 = 25;
+pub const MEAL_WEIGHT_GAIN: u8 = 1;
+pub const SNACK_WEIGHT_GAIN: u8 = 3;
+pub const PLAY_WEIGHT_LOSS: u8 = 1;
+pub const BIRTH_FITNESS: u8 = 50;
+pub const PLAY_FITNESS_GAIN: u8 = 5;
+pub const FRAIL_WEIGHT_DISTANCE: u8 = 10; // This far outside the ideal band is frail
+pub const FRAIL_FITNESS: u8 = 20;         // Fitness below this is frail
+
+// Daily sickness chance: base, +2% per weight point outside the ideal band,
+// +1% per 5 fitness below 50, capped
+pub const SICKNESS_BASE_PCT: u64 = 1;
+pub const MAX_SICKNESS_PCT: u64 = 50;
+
+// Pets per household (CareAll touches every one in a single transaction)
+pub const MAX_HOUSEHOLD_PETS: usize = 8;
+
+// How far ahead `forecast` looks for a death under no care
+pub const FORECAST_HORIZON_BLOCKS: u64 = 30 * 144;
+
+// Recent events kept per pet (ring buffer) so support can reconstruct a timeline
+pub const HISTORY_LEN: usize = 16;
+
+// Chance of each rarity tier (Common..Legendary) when deriving traits, in basis points
+pub const RARITY_ODDS_BPS: [u64; 5] = [6_000, 2_500, 1_000, 400, 100];
+
+// Rarity adjustments per tier, and the bounds species values stay within
+pub const RARITY_EARNING_BONUS_PCT: u16 = 5;  // Earns 5% more per tier
+pub const RARITY_DECAY_BONUS_PCT: u16 = 3;    // Decays 3% slower per tier
+pub const MIN_SPECIES_PCT: u16 = 50;
+pub const MAX_SPECIES_PCT: u16 = 200;
+
+/// How a species plays, as percentages of the GameConfig values
+/// (100 = unchanged; higher is faster decay/hunger, more earnings, longer stages)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Species {
+    pub name: &'static str,
+    pub decay_pct: u16,      // Health and happiness loss speed
+    pub hunger_pct: u16,     // Hunger gain speed
+    pub earning_pct: u16,    // Passive earning rate
+    pub evolution_pct: u16,  // Time at each level before evolving
+}
+
+/// Every species, indexed by `Traits.pet_type`
+pub const SPECIES: [Species; 4] = [
+    Species { name: "Satoshi", decay_pct: 100, hunger_pct: 100, earning_pct: 100, evolution_pct: 100 },
+    Species { name: "Miner", decay_pct: 110, hunger_pct: 125, earning_pct: 120, evolution_pct: 100 },
+    Species { name: "Hodler", decay_pct: 80, hunger_pct: 90, earning_pct: 90, evolution_pct: 120 },
+    Species { name: "Degen", decay_pct: 125, hunger_pct: 110, earning_pct: 130, evolution_pct: 85 },
+];
+
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct SatsgotchiState {
     // Identity
     pub inscription_id: String,
     pub owner: Pubkey,
+    pub name: String,         // "" = unnamed; reserved by a NameRecord
     
     // Game State
     pub level: Level,
@@ -64,33 +331,285 @@ pub struct SatsgotchiState {
     
     // Timing (using Bitcoin block heights)
     pub birth_block: u64,
+    pub age_blocks: u64,      // Blocks lived, excluding frozen time
     pub last_fed_block: u64,
     pub last_played_block: u64,
     pub last_cleaned_block: u64,
     pub last_update_block: u64,
+    pub died_block: u64,      // 0 while alive
     
     // Care Tracking
     pub care_mistakes: u8,
-    pub perfect_care_days: u16,
+    pub missed_feed_windows: u8, // Feed windows since the last meal already counted as mistakes
+    pub perfect_care_days: u16, // Days without neglect and in good shape
+    pub current_streak_days: u16, // Consecutive days without a care mistake
+    pub longest_streak_days: u16,
+    pub streak_history: Vec<StreakRecord>, // Most recent last
     pub poop_count: u8,
+    pub poop_since_block: u64, // When the current poop appeared
     pub sick: bool,
     
+    // Body condition
+    pub weight: u8,           // Meals and snacks add, play burns
+    pub fitness: u8,          // 0-100, built by play, fades daily
+    
+    // Support timeline
+    pub history: EventHistory,
+    
     // Earnings
     pub total_earned: u64,
     pub unclaimed_rewards: u64,
     pub care_multiplier: u16, // Basis points (100 = 1.0x)
+    pub household_bonus: u16, // Extra multiplier from living household pets
+    pub household_bonus_until: u64, // Block the last CareAll vouched for the bonus until
+    pub settled_rewards: Vec<SettledRewards>, // Owed to previous owners
+    pub reward_remainder: u64, // Sub-unit earnings carried to the next tick
     
     // Traits
     pub traits: Traits,
     
     // Evolution
     pub evolution_eligible_block: u64,
+    
+    // Delegated care (pet sitters)
+    pub caretakers: Vec<Caretaker>,
+    
+    // Vacation mode
+    pub frozen_until_block: u64,
+    pub total_frozen_blocks: u64,
+    
+    // Achievements (BADGE_* bitmask)
+    pub badges: u32,
+    
+    // Social
+    pub best_friendship: u8,  // Highest friendship level with any pet
+    
+    // Daily quests
+    pub quests: QuestProgress,
+    
+    // Game pauses already skipped over (GameConfig::total_paused_blocks)
+    pub paused_blocks_credited: u64,
+    
+    // Ownership
+    pub last_transfer_block: u64,
+    
+    // Runtime block of the last keeper reward this pet paid out
+    pub last_keeper_reward_block: u64,
+    
+    // Care action cooldowns
+    pub cooldowns: CareCooldowns,
+}
+
+/// Balance parameters for one life stage
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct LevelParams {
+    pub feed_threshold_blocks: u64, // Unfed longer than this = care mistake
+    pub death_neglect_days: u64,    // Dies when unfed for more days than this
+    pub health_decay_blocks: u64,   // Blocks per point of health lost
+    pub evolution_blocks: u64,      // Blocks at this level before it may evolve
+    pub evolution_reward: u64,      // Milestone $GOTCHI for evolving out of it
+    pub reward_rate_ppb: u64,       // Hourly earnings, parts per billion of circulating supply
+}
+
+/// Everything tunable about the game, governed through `GameConfig`
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct GameParams {
+    pub baby: LevelParams,
+    pub child: LevelParams,
+    pub teen: LevelParams,
+    pub adult: LevelParams,
+    pub senior: LevelParams,
+    pub ascended_reward_rate_ppb: u64,
+    
+    // Decay
+    pub hunger_period_blocks: u64,    // +1 hunger per period
+    pub happiness_period_blocks: u64, // -1 happiness per period
+    
+    // Burns (9 decimals)
+    pub freeze_burn_per_block: u64,
+    pub rename_burn: u64,
+    
+    // Ownership
+    pub transfer_cooldown_blocks: u64, // Minimum gap between ownership transfers
+    
+    // Daily quests
+    pub quest_reward: u64,             // Per completed quest, from the earning pool
+    
+    // Care streaks (added to care_multiplier for earnings)
+    pub streak_bonus_per_day: u16,     // Multiplier points per streak day
+    pub max_streak_bonus: u16,
+    
+    // Bitcoin events (effect windows start at the event block)
+    pub halving_bonus_blocks: u64,             // Earnings doubled
+    pub difficulty_window_blocks: u64,         // Early evolution allowed
+    pub difficulty_evolution_early_blocks: u64,
+    
+    // Social visits (happiness for both pets, once per pair per cooldown)
+    pub visit_happiness: u8,
+    pub friendship_happiness_per_level: u8,
+    pub visit_cooldown_blocks: u64,
+    
+    // Names of dead pets are released after this long
+    pub name_grace_blocks: u64,
+    
+    // Household bonus (added to care_multiplier for earnings)
+    pub household_bonus_per_pet: u16,     // Per living pet beyond the first
+    pub max_household_bonus: u16,
+    
+    // Mempool weather (extra happiness change per period)
+    pub storm_drain_period_blocks: u64,   // -1 happiness per period in a storm
+    pub quiet_boost_period_blocks: u64,   // +1 happiness per period when quiet
+}
+
+/// On-chain game configuration. Parameter updates are queued by the admin
+/// and only take effect `timelock_blocks` later; the guardian can pause
+/// the game immediately.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct GameConfig {
+    pub admin: Pubkey,
+    pub oracle: Pubkey,       // Ordinals oracle that syncs sales (TransferOwnership)
+    pub token_state: Pubkey,  // $GOTCHI TokenState account (circulating supply)
+    pub timelock_blocks: u64,
+    pub params: GameParams,
+    pub pending_params: Option<GameParams>,
+    pub pending_effective_block: u64,
+    
+    // Emergency pause
+    pub guardian: Pubkey,
+    pub paused: bool,
+    pub paused_at_block: u64,
+    pub paused_blocks: u64,   // Length of all finished pauses
+}
+
+/// Mempool conditions, as classified by the oracle from recent block fee
+/// rates and fullness
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq)]
+pub enum Weather {
+    Quiet,   // Cheap, half-empty blocks
+    Normal,
+    Storm,   // Fee spike, full blocks
+}
+
+/// One visit between two friends
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct VisitRecord {
+    pub visitor: Pubkey,      // Pet state account that came over
+    pub block: u64,
+}
+
+/// Friendship between two pets, grown by visits in either direction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct Friendship {
+    pub pets: [Pubkey; 2],    // Pet state accounts, lower key first
+    pub visits: u32,
+    pub level: u8,            // 0-MAX_FRIENDSHIP_LEVEL
+    pub last_visit_block: u64,
+    pub log: Vec<VisitRecord>, // Most recent last, at most MAX_VISIT_LOG
+}
+
+impl Friendship {
+    pub fn new(pet_a: Pubkey, pet_b: Pubkey) -> Self {
+        Self {
+            pets: if pet_a < pet_b { [pet_a, pet_b] } else { [pet_b, pet_a] },
+            visits: 0,
+            level: 0,
+            last_visit_block: 0,
+            log: Vec::new(),
+        }
+    }
+}
+
+/// One weather reading published by the oracle
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct WeatherReading {
+    pub weather: Weather,
+    pub observed_block: u64,  // Bitcoin block the reading was taken at
+}
+
+/// Recent weather readings, written only by the weather oracle
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct WeatherState {
+    pub oracle: Pubkey,
+    pub readings: Vec<WeatherReading>, // Oldest first, at most MAX_WEATHER_READINGS
+}
+
+impl WeatherState {
+    /// Latest reading, if the oracle has published any
+    pub fn latest(&self) -> Option<&WeatherReading> {
+        self.readings.last()
+    }
+    
+    /// Add a newer reading, dropping the oldest once the history is full
+    pub fn push(&mut self, reading: WeatherReading) -> Result<(), ProgramError> {
+        // Readings only move forward
+        if let Some(latest) = self.latest() {
+            if reading.observed_block <= latest.observed_block {
+                return Err(ProgramError::InvalidArgument);
+            }
+        }
+        
+        self.readings.push(reading);
+        if self.readings.len() > MAX_WEATHER_READINGS {
+            self.readings.remove(0);
+        }
+        
+        Ok(())
+    }
+}
+
+/// Every badge an owner's pets have unlocked
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct OwnerAchievements {
+    pub owner: Pubkey,
+    pub badges: u32,
+    pub longest_streak_days: u16, // Best streak on any pet while they owned it
+}
+
+/// An owner's pets, cared for together with CareAll (PDA of HOUSEHOLD_SEED + owner)
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct Household {
+    pub owner: Pubkey,
+    pub pets: Vec<Pubkey>,        // Pet state accounts, in CareAll order
+    pub pets_adopted: u32,        // Lifetime: pets ever added
+    pub total_care_actions: u64,  // Lifetime: care given through CareAll
+    
+    // Snapshot as of the last CareAll
+    pub alive_pets: u8,
+    pub total_earned: u64,        // Across current member pets
+    pub updated_block: u64,
+}
+
+/// Reservation of a pet name (PDA of NAME_SEED + the lowercased name).
+/// An all-zero account is a free name.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct NameRecord {
+    pub name: String,         // As the owner wrote it
+    pub pet: Pubkey,          // Pet state account holding the name
+    pub claimed_block: u64,
+}
+
+/// Stats and pending rewards for an account that cranks pet updates
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq)]
+pub struct KeeperState {
+    pub keeper: Pubkey,
+    pub total_updates: u64,       // Pet updates submitted
+    pub rewarded_updates: u64,    // Updates that earned a reward
+    pub total_rewards: u64,
+    pub unclaimed_rewards: u64,
+    pub last_reward_block: u64,
+    pub rewards_this_block: u8,
 }
 
 // ============================================================================
 // INSTRUCTION DEFINITIONS
 // ============================================================================
 
+// Every instruction except RemoveCaretaker takes the GameConfig account
+// (see `config_address`) as its first account. Instructions that advance
+// pet time also take the $GOTCHI TokenState account right after it,
+// followed by the weather account (see `weather_address`).
+// Instructions that burn $GOTCHI take the payer's token account and the
+// token program right after the signer who pays (see `burn_gotchi`).
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub enum SatsgotchiInstruction {
     /// Initialize a new Satsgotchi
@@ -99,7 +618,7 @@ pub enum SatsgotchiInstruction {
         traits: Traits,
     },
     
-    /// Feed the Satsgotchi
+    /// Feed the Satsgotchi a meal
     Feed,
     
     /// Play with Satsgotchi
@@ -112,6 +631,7 @@ pub enum SatsgotchiInstruction {
     Medicine,
     
     /// Update state based on time elapsed
+    /// (append [keeper_account, keeper_signer] to earn a keeper reward)
     UpdateState {
         current_block: u64,
     },
@@ -126,12 +646,152 @@ pub enum SatsgotchiInstruction {
     TransferOwnership {
         new_owner: Pubkey,
     },
+    
+    /// Delegate care actions to a pet sitter until `expires_block`
+    AddCaretaker {
+        caretaker: Pubkey,
+        expires_block: u64,
+        allowed_actions: u8,
+    },
+    
+    /// Revoke a pet sitter
+    RemoveCaretaker {
+        caretaker: Pubkey,
+    },
+    
+    /// Vacation mode: pause decay and earnings for `blocks` (burns $GOTCHI)
+    Freeze {
+        blocks: u64,
+    },
+    
+    /// Update every pet account passed in (keeper crank)
+    BatchUpdateState {
+        current_block: u64,
+    },
+    
+    /// Create the keeper stats account for the signer
+    RegisterKeeper,
+    
+    /// Claim accumulated keeper rewards
+    ClaimKeeperRewards,
+    
+    /// Create the GameConfig account with the signer as admin (run at deploy)
+    InitializeConfig {
+        timelock_blocks: u64,
+        params: GameParams,
+        guardian: Pubkey,
+        token_state: Pubkey,
+        oracle: Pubkey,
+    },
+    
+    /// Admin: queue new game parameters, active once the timelock passes
+    ProposeConfigUpdate {
+        params: GameParams,
+    },
+    
+    /// Admin: drop the queued parameters before they take effect
+    CancelConfigUpdate,
+    
+    /// Guardian: pause or resume the game
+    SetPaused {
+        paused: bool,
+    },
+    
+    /// Previous owner: claim rewards settled to them when the pet was sold
+    ClaimSettledRewards,
+    
+    /// Create the signer's achievements account. Owner-signed pet
+    /// instructions accept it as an optional last account to record badges.
+    RegisterOwnerAchievements,
+    
+    /// Admin: create the weather account or hand it to a new oracle
+    SetWeatherOracle {
+        oracle: Pubkey,
+    },
+    
+    /// Weather oracle: publish the mempool weather seen at `observed_block`
+    PublishWeather {
+        weather: Weather,
+        observed_block: u64,
+    },
+    
+    /// Give a snack: cheers the pet up and adds weight, but isn't a meal
+    /// (same accounts, permission and cooldown as Feed)
+    Snack,
+    
+    /// Log the current $GOTCHI burn of every care action (read-only)
+    Quote,
+    
+    /// Log when the pet's next care mistake, death (under no care) and
+    /// evolution would happen (read-only)
+    Forecast,
+    
+    /// Create the signer's household account (its PDA)
+    RegisterHousehold,
+    
+    /// Owner: add one of their pets to their household
+    AddToHousehold,
+    
+    /// Owner: take a pet out of their household (drops its household bonus)
+    RemoveFromHousehold,
+    
+    /// Owner: give every household pet the same care action (a CARE_* flag)
+    /// for the action's burn per pet actually cared for
+    CareAll {
+        action: u8,
+    },
+    
+    /// Owner: name (or rename) the pet, reserving the name in its
+    /// NameRecord and releasing the old one
+    SetName {
+        name: String,
+    },
+    
+    /// Anyone: free the name of a pet that has been dead for the grace period
+    ReleaseName,
+    
+    /// Owner: take their pet to visit another owner's pet. Both gain
+    /// happiness and their friendship grows (once per pair per day).
+    Visit {
+        host_pet: Pubkey,
+    },
+    
+    /// Admin: hand Ordinal sale syncing (TransferOwnership) to a new oracle
+    SetTransferOracle {
+        oracle: Pubkey,
+    },
+}
+
+impl SatsgotchiInstruction {
+    /// Instructions that still work while the game is paused: syncing
+    /// Ordinal sales and weather, revoking pet sitters, read-only quotes and
+    /// running the config itself
+    pub fn allowed_while_paused(&self) -> bool {
+        matches!(
+            self,
+            SatsgotchiInstruction::TransferOwnership { .. }
+                | SatsgotchiInstruction::RemoveCaretaker { .. }
+                | SatsgotchiInstruction::InitializeConfig { .. }
+                | SatsgotchiInstruction::ProposeConfigUpdate { .. }
+                | SatsgotchiInstruction::CancelConfigUpdate
+                | SatsgotchiInstruction::SetPaused { .. }
+                | SatsgotchiInstruction::SetWeatherOracle { .. }
+                | SatsgotchiInstruction::SetTransferOracle { .. }
+                | SatsgotchiInstruction::PublishWeather { .. }
+                | SatsgotchiInstruction::Quote
+                | SatsgotchiInstruction::Forecast
+        )
+    }
 }
 
 // ============================================================================
 // PROGRAM ENTRYPOINT
 // ============================================================================
 
+#[cfg(not(feature = "no-entrypoint"))]
+use arch_program::entrypoint;
+
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -142,12 +802,17 @@ pub fn process_instruction(
     let instruction = SatsgotchiInstruction::try_from_slice(instruction_data)
         .map_err(|_| ProgramError::InvalidInstructionData)?;
 
+    // Everything outside the allowlist is blocked while the game is paused
+    if !instruction.allowed_while_paused() {
+        check_not_paused(program_id, accounts)?;
+    }
+
     match instruction {
         SatsgotchiInstruction::Initialize { inscription_id, traits } => {
             process_initialize(program_id, accounts, inscription_id, traits)
         }
         SatsgotchiInstruction::Feed => {
-            process_feed(program_id, accounts)
+            process_feed(program_id, accounts, false)
         }
         SatsgotchiInstruction::Play => {
             process_play(program_id, accounts)
@@ -170,6 +835,81 @@ pub fn process_instruction(
         SatsgotchiInstruction::TransferOwnership { new_owner } => {
             process_transfer_ownership(program_id, accounts, new_owner)
         }
+        SatsgotchiInstruction::AddCaretaker { caretaker, expires_block, allowed_actions } => {
+            process_add_caretaker(program_id, accounts, caretaker, expires_block, allowed_actions)
+        }
+        SatsgotchiInstruction::RemoveCaretaker { caretaker } => {
+            process_remove_caretaker(program_id, accounts, caretaker)
+        }
+        SatsgotchiInstruction::Freeze { blocks } => {
+            process_freeze(program_id, accounts, blocks)
+        }
+        SatsgotchiInstruction::BatchUpdateState { current_block } => {
+            process_batch_update_state(program_id, accounts, current_block)
+        }
+        SatsgotchiInstruction::RegisterKeeper => {
+            process_register_keeper(program_id, accounts)
+        }
+        SatsgotchiInstruction::ClaimKeeperRewards => {
+            process_claim_keeper_rewards(program_id, accounts)
+        }
+        SatsgotchiInstruction::InitializeConfig { timelock_blocks, params, guardian, token_state, oracle } => {
+            process_initialize_config(program_id, accounts, timelock_blocks, params, guardian, token_state, oracle)
+        }
+        SatsgotchiInstruction::ProposeConfigUpdate { params } => {
+            process_propose_config_update(program_id, accounts, params)
+        }
+        SatsgotchiInstruction::CancelConfigUpdate => {
+            process_cancel_config_update(program_id, accounts)
+        }
+        SatsgotchiInstruction::SetPaused { paused } => {
+            process_set_paused(program_id, accounts, paused)
+        }
+        SatsgotchiInstruction::ClaimSettledRewards => {
+            process_claim_settled_rewards(program_id, accounts)
+        }
+        SatsgotchiInstruction::RegisterOwnerAchievements => {
+            process_register_owner_achievements(program_id, accounts)
+        }
+        SatsgotchiInstruction::SetWeatherOracle { oracle } => {
+            process_set_weather_oracle(program_id, accounts, oracle)
+        }
+        SatsgotchiInstruction::PublishWeather { weather, observed_block } => {
+            process_publish_weather(program_id, accounts, weather, observed_block)
+        }
+        SatsgotchiInstruction::Snack => {
+            process_feed(program_id, accounts, true)
+        }
+        SatsgotchiInstruction::Quote => {
+            process_quote(program_id, accounts)
+        }
+        SatsgotchiInstruction::Forecast => {
+            process_forecast(program_id, accounts)
+        }
+        SatsgotchiInstruction::RegisterHousehold => {
+            process_register_household(program_id, accounts)
+        }
+        SatsgotchiInstruction::AddToHousehold => {
+            process_add_to_household(program_id, accounts)
+        }
+        SatsgotchiInstruction::RemoveFromHousehold => {
+            process_remove_from_household(program_id, accounts)
+        }
+        SatsgotchiInstruction::CareAll { action } => {
+            process_care_all(program_id, accounts, action)
+        }
+        SatsgotchiInstruction::SetName { name } => {
+            process_set_name(program_id, accounts, name)
+        }
+        SatsgotchiInstruction::ReleaseName => {
+            process_release_name(program_id, accounts)
+        }
+        SatsgotchiInstruction::Visit { host_pet } => {
+            process_visit(program_id, accounts, host_pet)
+        }
+        SatsgotchiInstruction::SetTransferOracle { oracle } => {
+            process_set_transfer_oracle(program_id, accounts, oracle)
+        }
     }
 }
 
@@ -178,13 +918,14 @@ pub fn process_instruction(
 // ============================================================================
 
 pub fn process_initialize(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     inscription_id: String,
     traits: Traits,
 ) -> Result<(), ProgramError> {
     let account_iter = &mut accounts.iter();
     
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     
@@ -193,34 +934,19 @@ pub fn process_initialize(
         return Err(ProgramError::MissingRequiredSignature);
     }
     
-    // Get current Bitcoin block height from runtime
-    // In Arch, this comes from the Bitcoin blockchain
-    let current_block = 800_000u64; // Will be actual Bitcoin block in production
+    // Traits come from the inscription itself; the caller only restates them
+    if traits != derive_traits(&inscription_id)? {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    let current_block = get_current_block();
+    
+    let config = load_config(config_account, program_id)?;
+    let params = config.active_params(current_block);
     
     // Create initial state
-    let state = SatsgotchiState {
-        inscription_id,
-        owner: *owner_account.key,
-        level: Level::Baby,
-        status: Status::Alive,
-        health: 100,
-        happiness: 100,
-        hunger: 0,
-        birth_block: current_block,
-        last_fed_block: current_block,
-        last_played_block: current_block,
-        last_cleaned_block: current_block,
-        last_update_block: current_block,
-        care_mistakes: 0,
-        perfect_care_days: 0,
-        poop_count: 0,
-        sick: false,
-        total_earned: 0,
-        unclaimed_rewards: 0,
-        care_multiplier: 100, // 1.0x
-        traits,
-        evolution_eligible_block: current_block + 1_008, // ~7 days (144 blocks/day)
-    };
+    let mut state = SatsgotchiState::new(inscription_id, *owner_account.key, traits, params, current_block);
+    state.paused_blocks_credited = config.total_paused_blocks(current_block);
     
     // Serialize state to account data
     let serialized_state = state.try_to_vec()
@@ -235,50 +961,58 @@ pub fn process_initialize(
 }
 
 pub fn process_feed(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
+    snack: bool,
 ) -> Result<(), ProgramError> {
     let account_iter = &mut accounts.iter();
     
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let weather = next_weather_account(program_id, account_iter)?;
     let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
-    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let signer_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     let gotchi_token_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_program_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     
-    // Verify owner
-    if !owner_account.is_signer {
+    // Owner or a delegated caretaker signs (and pays the burn)
+    if !signer_account.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
     
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    let params = config.active_params(current_block);
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
+    
     // Deserialize current state
     let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
         .map_err(|_| ProgramError::InvalidAccountData)?;
     
-    // Verify ownership
-    if state.owner != *owner_account.key {
-        return Err(ProgramError::IllegalOwner);
-    }
+    // Verify ownership or delegation
+    authorize_care(&state, signer_account.key, CARE_FEED, current_block)?;
     
-    // Check if alive
-    if state.status == Status::Dead {
-        return Err(ProgramError::Custom(1)); // Cannot feed dead pet
-    }
+    check_cooldown(&state, CARE_FEED, current_block)?;
     
-    // Calculate burn amount (dynamic based on circulating supply)
-    // In production, this would query token supply from $GOTCHI program
-    let burn_amount = calculate_burn_amount("feed");
+    // Paused game time never counts against the pet
+    credit_paused_blocks(&mut state, config.total_paused_blocks(current_block));
     
-    // TODO: Burn $GOTCHI tokens via CPI to token program
-    // For now, we assume this is handled
+    // Catch up on decay since the last update (same tick as UpdateState);
+    // fails if the pet has died in the meantime
+    let tick = settle_elapsed_time(&mut state, params, circulating_supply, weather.as_ref(), current_block)?;
+    log_tick(&state, &tick);
     
-    // Update state
-    state.hunger = state.hunger.saturating_sub(50);
-    state.health = (state.health + 10).min(100);
-    state.last_fed_block = get_current_block();
+    // Burn scales with circulating supply (the amount Quote reports)
+    let burn_amount = calculate_burn_amount(params, circulating_supply, if snack { "snack" } else { "feed" });
+    burn_gotchi(token_program_account, gotchi_token_account, signer_account, token_state_account, burn_amount)?;
     
-    // Random poop generation (20% chance)
-    if is_poop_generated() {
-        state.poop_count = (state.poop_count + 1).min(8);
+    // Update state (fails if the pet is dead)
+    if snack {
+        apply_snack(&mut state, current_block)?;
+    } else {
+        apply_feed(&mut state, current_block)?;
     }
+    log_quest_rewards(settle_quests(&mut state, params, current_block));
     
     // Serialize updated state
     let serialized_state = state.try_to_vec()
@@ -286,208 +1020,366 @@ pub fn process_feed(
     
     add_state_transition(state_account, serialized_state);
     
-    msg!("Fed Satsgotchi. Hunger: {}, Health: {}", state.hunger, state.health);
+    // Optional last account: the owner's achievements record
+    record_owner_achievements(account_iter.next(), &state)?;
+    
+    msg!("Fed Satsgotchi {}. Hunger: {}, Health: {}, Weight: {}",
+        if snack { "a snack" } else { "a meal" }, state.hunger, state.health, state.weight);
     
     Ok(())
 }
 
 pub fn process_play(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> Result<(), ProgramError> {
     let account_iter = &mut accounts.iter();
     
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let weather = next_weather_account(program_id, account_iter)?;
     let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
-    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let signer_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let gotchi_token_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_program_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     
-    if !owner_account.is_signer {
+    if !signer_account.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
     
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    let params = config.active_params(current_block);
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
+    
     let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
         .map_err(|_| ProgramError::InvalidAccountData)?;
     
-    if state.owner != *owner_account.key {
-        return Err(ProgramError::IllegalOwner);
-    }
+    authorize_care(&state, signer_account.key, CARE_PLAY, current_block)?;
     
-    if state.status == Status::Dead {
-        return Err(ProgramError::Custom(1));
-    }
+    check_cooldown(&state, CARE_PLAY, current_block)?;
+    
+    // Paused game time never counts against the pet
+    credit_paused_blocks(&mut state, config.total_paused_blocks(current_block));
     
-    // Burn tokens (dynamic amount)
-    let _burn_amount = calculate_burn_amount("play");
+    // Catch up on decay since the last update (same tick as UpdateState);
+    // fails if the pet has died in the meantime
+    let tick = settle_elapsed_time(&mut state, params, circulating_supply, weather.as_ref(), current_block)?;
+    log_tick(&state, &tick);
+    
+    // Burn scales with circulating supply (the amount Quote reports)
+    let burn_amount = calculate_burn_amount(params, circulating_supply, "play");
+    burn_gotchi(token_program_account, gotchi_token_account, signer_account, token_state_account, burn_amount)?;
     
     // Update happiness
-    state.happiness = (state.happiness + 20).min(100);
-    state.last_played_block = get_current_block();
+    apply_play(&mut state, current_block)?;
+    log_quest_rewards(settle_quests(&mut state, params, current_block));
     
     let serialized_state = state.try_to_vec()
         .map_err(|_| ProgramError::InvalidAccountData)?;
     
     add_state_transition(state_account, serialized_state);
     
+    // Optional last account: the owner's achievements record
+    record_owner_achievements(account_iter.next(), &state)?;
+    
     msg!("Played with Satsgotchi. Happiness: {}", state.happiness);
     
     Ok(())
 }
 
 pub fn process_clean(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> Result<(), ProgramError> {
     let account_iter = &mut accounts.iter();
     
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let weather = next_weather_account(program_id, account_iter)?;
     let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
-    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let signer_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let gotchi_token_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_program_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     
-    if !owner_account.is_signer {
+    if !signer_account.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
     
-    let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    let params = config.active_params(current_block);
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
+    
+    let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
         .map_err(|_| ProgramError::InvalidAccountData)?;
     
-    if state.owner != *owner_account.key {
-        return Err(ProgramError::IllegalOwner);
-    }
+    authorize_care(&state, signer_account.key, CARE_CLEAN, current_block)?;
     
-    if state.status == Status::Dead {
-        return Err(ProgramError::Custom(1));
-    }
+    check_cooldown(&state, CARE_CLEAN, current_block)?;
+    
+    // Paused game time never counts against the pet
+    credit_paused_blocks(&mut state, config.total_paused_blocks(current_block));
     
-    let _burn_amount = calculate_burn_amount("clean");
+    // Catch up on decay since the last update (same tick as UpdateState);
+    // fails if the pet has died in the meantime
+    let tick = settle_elapsed_time(&mut state, params, circulating_supply, weather.as_ref(), current_block)?;
+    log_tick(&state, &tick);
+    
+    let burn_amount = calculate_burn_amount(params, circulating_supply, "clean");
+    burn_gotchi(token_program_account, gotchi_token_account, signer_account, token_state_account, burn_amount)?;
     
     // Clean up poops
-    state.poop_count = 0;
-    state.health = (state.health + 10).min(100);
-    state.last_cleaned_block = get_current_block();
+    apply_clean(&mut state, current_block)?;
+    log_quest_rewards(settle_quests(&mut state, params, current_block));
     
     let serialized_state = state.try_to_vec()
         .map_err(|_| ProgramError::InvalidAccountData)?;
     
     add_state_transition(state_account, serialized_state);
     
+    // Optional last account: the owner's achievements record
+    record_owner_achievements(account_iter.next(), &state)?;
+    
     msg!("Cleaned Satsgotchi. Health: {}", state.health);
     
     Ok(())
 }
 
 pub fn process_medicine(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> Result<(), ProgramError> {
     let account_iter = &mut accounts.iter();
     
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let weather = next_weather_account(program_id, account_iter)?;
     let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
-    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let signer_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let gotchi_token_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_program_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     
-    if !owner_account.is_signer {
+    if !signer_account.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
     
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    let params = config.active_params(current_block);
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
+    
     let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
         .map_err(|_| ProgramError::InvalidAccountData)?;
     
-    if state.owner != *owner_account.key {
-        return Err(ProgramError::IllegalOwner);
-    }
+    authorize_care(&state, signer_account.key, CARE_MEDICINE, current_block)?;
     
-    if state.status == Status::Dead {
-        return Err(ProgramError::Custom(1));
-    }
+    check_cooldown(&state, CARE_MEDICINE, current_block)?;
+    
+    // Paused game time never counts against the pet
+    credit_paused_blocks(&mut state, config.total_paused_blocks(current_block));
+    
+    // Catch up on decay since the last update (same tick as UpdateState);
+    // fails if the pet has died in the meantime
+    let tick = settle_elapsed_time(&mut state, params, circulating_supply, weather.as_ref(), current_block)?;
+    log_tick(&state, &tick);
     
-    let _burn_amount = calculate_burn_amount("medicine");
+    let burn_amount = calculate_burn_amount(params, circulating_supply, "medicine");
+    burn_gotchi(token_program_account, gotchi_token_account, signer_account, token_state_account, burn_amount)?;
     
     // Cure sickness and restore health
-    state.sick = false;
-    state.health = (state.health + 40).min(100);
+    apply_medicine(&mut state, current_block)?;
     
     let serialized_state = state.try_to_vec()
         .map_err(|_| ProgramError::InvalidAccountData)?;
     
     add_state_transition(state_account, serialized_state);
     
+    // Optional last account: the owner's achievements record
+    record_owner_achievements(account_iter.next(), &state)?;
+    
     msg!("Gave medicine. Health: {}", state.health);
     
     Ok(())
 }
 
 pub fn process_update_state(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     current_block: u64,
 ) -> Result<(), ProgramError> {
-    let account_iter = &mut accounts.iter();
+    let (pet_accounts, keeper_accounts) = split_keeper_accounts(accounts);
     
-    let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let [config_account, token_state_account, weather_account, state_account, ..] = pet_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let weather = load_weather(program_id, weather_account)?;
     
-    let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let config = load_config(config_account, program_id)?;
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
     
-    if state.status == Status::Dead {
-        return Ok(()); // Dead pets don't update
+    let mut keeper = match keeper_accounts {
+        Some((keeper_account, keeper_signer)) => Some(load_keeper(keeper_account, keeper_signer)?),
+        None => None,
+    };
+    
+    let (state, outcome) = tick_pet_account(
+        state_account, &config, circulating_supply, weather.as_ref(), keeper.as_mut(), current_block,
+    )?;
+    
+    log_tick(&state, &outcome);
+    
+    if let (Some(keeper), Some((keeper_account, _))) = (keeper, keeper_accounts) {
+        let serialized_keeper = keeper.try_to_vec()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        
+        add_state_transition(keeper_account, serialized_keeper);
     }
     
-    // Calculate time elapsed in blocks
-    let blocks_elapsed = current_block.saturating_sub(state.last_update_block);
+    Ok(())
+}
+
+pub fn process_batch_update_state(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    current_block: u64,
+) -> Result<(), ProgramError> {
+    let (pet_accounts, keeper_accounts) = split_keeper_accounts(accounts);
+    let [config_account, token_state_account, weather_account, pet_accounts @ ..] = pet_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let weather = load_weather(program_id, weather_account)?;
     
-    if blocks_elapsed == 0 {
-        return Ok(());
+    if pet_accounts.is_empty() {
+        return Err(ProgramError::NotEnoughAccountKeys);
     }
     
-    // Update hunger (increases over time)
-    let hunger_increase = (blocks_elapsed / 144) as u8; // Per day
-    state.hunger = (state.hunger + hunger_increase).min(100);
+    if pet_accounts.len() > MAX_BATCH_PETS {
+        return Err(ProgramError::InvalidArgument);
+    }
     
-    // Decay health based on level
-    let health_decay = match state.level {
-        Level::Baby => (blocks_elapsed / 288) as u8,    // 0.5/day
-        Level::Child => (blocks_elapsed / 144) as u8,    // 1/day
-        Level::Teen => (blocks_elapsed / 96) as u8,     // 1.5/day
-        Level::Adult => (blocks_elapsed / 72) as u8,    // 2/day
-        Level::Senior => (blocks_elapsed / 48) as u8,   // 3/day
-        _ => 0,
+    let config = load_config(config_account, program_id)?;
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
+    
+    let mut keeper = match keeper_accounts {
+        Some((keeper_account, keeper_signer)) => Some(load_keeper(keeper_account, keeper_signer)?),
+        None => None,
     };
-    state.health = state.health.saturating_sub(health_decay);
     
-    // Decay happiness
-    state.happiness = state.happiness.saturating_sub((blocks_elapsed / 144) as u8);
+    let (mut updated, mut skipped, mut died, mut mistakes, mut ready) = (0u32, 0u32, 0u32, 0u32, 0u32);
     
-    // Check for care mistakes (neglect)
-    let feed_threshold = get_feed_threshold(&state.level);
-    let blocks_since_fed = current_block.saturating_sub(state.last_fed_block);
+    // Log one line per pet for the crank
+    for state_account in pet_accounts {
+        let (state, outcome) = tick_pet_account(
+            state_account, &config, circulating_supply, weather.as_ref(), keeper.as_mut(), current_block,
+        )?;
+        
+        if state.status == Status::Dead && !outcome.died {
+            skipped += 1;
+            msg!("{}: skipped (dead)", state.inscription_id);
+            continue;
+        }
+        
+        let evolve_ready = is_evolution_ready(&state, current_block);
+        
+        updated += 1;
+        died += outcome.died as u32;
+        mistakes += outcome.care_mistake as u32;
+        ready += evolve_ready as u32;
+        
+        msg!("{}: blocks={} frozen={} care_mistake={} died={} evolve_ready={}",
+            state.inscription_id, outcome.blocks_elapsed, outcome.frozen_blocks,
+            outcome.care_mistake, outcome.died, evolve_ready);
+    }
     
-    if blocks_since_fed > feed_threshold {
-        state.care_mistakes += 1;
-        msg!("Care mistake! Total: {}", state.care_mistakes);
+    if let (Some(keeper), Some((keeper_account, _))) = (keeper, keeper_accounts) {
+        let serialized_keeper = keeper.try_to_vec()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        
+        add_state_transition(keeper_account, serialized_keeper);
     }
     
-    // Check for death
-    if state.health == 0 || should_die(&state, current_block) {
-        state.status = Status::Dead;
-        msg!("Satsgotchi died!");
+    msg!("Batch update at block {}: {} updated, {} skipped, {} died, {} care mistakes, {} ready to evolve",
+        current_block, updated, skipped, died, mistakes, ready);
+    
+    Ok(())
+}
+
+pub fn process_register_keeper(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    // GameConfig (pause check happens in process_instruction)
+    let _config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let keeper_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let keeper_signer = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    if !keeper_signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
     }
     
-    // Accumulate rewards
-    accumulate_rewards(&mut state, blocks_elapsed);
+    // Never wipe an existing keeper (or anything else)
+    if !is_zeroed(keeper_account) {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
     
-    state.last_update_block = current_block;
+    let keeper = KeeperState::new(*keeper_signer.key);
     
-    let serialized_state = state.try_to_vec()
+    let serialized_keeper = keeper.try_to_vec()
         .map_err(|_| ProgramError::InvalidAccountData)?;
     
-    add_state_transition(state_account, serialized_state);
+    add_state_transition(keeper_account, serialized_keeper);
+    
+    msg!("Keeper registered: {:?}", keeper.keeper);
     
     Ok(())
 }
 
-pub fn process_evolve(
+pub fn process_claim_keeper_rewards(
     _program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> Result<(), ProgramError> {
     let account_iter = &mut accounts.iter();
     
+    // GameConfig (pause check happens in process_instruction)
+    let _config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let keeper_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let keeper_signer = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let _token_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    let mut keeper = load_keeper(keeper_account, keeper_signer)?;
+    
+    if keeper.unclaimed_rewards == 0 {
+        return Err(ProgramError::Custom(4)); // No rewards to claim
+    }
+    
+    let amount = keeper.unclaimed_rewards;
+    keeper.unclaimed_rewards = 0;
+    
+    // Mint from the keeper pool via CPI
+    // TODO: Implement CPI to token program (MintKeeper)
+    
+    let serialized_keeper = keeper.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(keeper_account, serialized_keeper);
+    
+    msg!("Keeper claimed {} $GOTCHI rewards", amount);
+    
+    Ok(())
+}
+
+pub fn process_evolve(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let weather = next_weather_account(program_id, account_iter)?;
     let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     let milestone_rewards_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
@@ -504,50 +1396,46 @@ pub fn process_evolve(
     }
     
     let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    let params = config.active_params(current_block);
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
     
-    // Check if eligible for evolution
-    if current_block < state.evolution_eligible_block {
-        return Err(ProgramError::Custom(2)); // Not ready to evolve
-    }
-    
-    // Evolve to next level
-    let (new_level, reward_amount, next_evolution_blocks) = match state.level {
-        Level::Baby => (Level::Child, 50, 4_032),        // 50 $GOTCHI, 28 days
-        Level::Child => (Level::Teen, 250, 17_280),      // 250 $GOTCHI, ~120 days
-        Level::Teen => (Level::Adult, 1_500, 17_280),   // 1,500 $GOTCHI, ~120 days
-        Level::Adult => (Level::Senior, 25_000, 11_520), // 25,000 $GOTCHI, ~80 days
-        Level::Senior => (Level::Ascended, 2_000_000, 0), // 2M $GOTCHI, immortal
-        _ => return Err(ProgramError::Custom(3)), // Already at max level
-    };
+    // Paused game time never counts against the pet
+    credit_paused_blocks(&mut state, config.total_paused_blocks(current_block));
     
-    state.level = new_level;
-    state.evolution_eligible_block = if next_evolution_blocks > 0 {
-        current_block + next_evolution_blocks
-    } else {
-        u64::MAX // Ascended = no more evolution
-    };
+    // Catch up on decay since the last update (same tick as UpdateState);
+    // fails if the pet has died in the meantime
+    let tick = settle_elapsed_time(&mut state, params, circulating_supply, weather.as_ref(), current_block)?;
+    log_tick(&state, &tick);
     
     // Mint milestone reward via CPI to token program
     // TODO: Implement CPI to $GOTCHI token program
-    
-    state.total_earned += reward_amount;
+    let badges_before = state.badges;
+    let reward_amount = apply_evolve(&mut state, params, current_block)?;
+    log_badges(state.badges & !badges_before);
     
     let serialized_state = state.try_to_vec()
         .map_err(|_| ProgramError::InvalidAccountData)?;
     
     add_state_transition(state_account, serialized_state);
     
-    msg!("Evolved to {:?}! Reward: {} $GOTCHI", state.level, reward_amount);
+    // Optional last account: the owner's achievements record
+    record_owner_achievements(account_iter.next(), &state)?;
+    
+    msg!("Evolved to {:?}! Reward: {} $GOTCHI, vitality: {}", state.level, reward_amount, vitality_score(&state));
     
     Ok(())
 }
 
 pub fn process_claim_rewards(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> Result<(), ProgramError> {
     let account_iter = &mut accounts.iter();
     
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let weather = next_weather_account(program_id, account_iter)?;
     let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     let token_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
@@ -563,6 +1451,18 @@ pub fn process_claim_rewards(
         return Err(ProgramError::IllegalOwner);
     }
     
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    let params = config.active_params(current_block);
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
+    
+    // Paused game time never counts against the pet
+    credit_paused_blocks(&mut state, config.total_paused_blocks(current_block));
+    
+    // Accrue rewards up to now first; dead pets can still claim what they earned
+    let tick = apply_tick(&mut state, params, circulating_supply, weather.as_ref(), current_block);
+    log_tick(&state, &tick);
+    
     if state.unclaimed_rewards == 0 {
         return Err(ProgramError::Custom(4)); // No rewards to claim
     }
@@ -578,144 +1478,3991 @@ pub fn process_claim_rewards(
     
     add_state_transition(state_account, serialized_state);
     
+    // Optional last account: the owner's achievements record
+    record_owner_achievements(account_iter.next(), &state)?;
+    
     msg!("Claimed {} $GOTCHI rewards", amount);
     
     Ok(())
 }
 
-pub fn process_transfer_ownership(
+pub fn process_claim_settled_rewards(
     _program_id: &Pubkey,
     accounts: &[AccountInfo],
-    new_owner: Pubkey,
 ) -> Result<(), ProgramError> {
     let account_iter = &mut accounts.iter();
     
+    // GameConfig (pause check happens in process_instruction)
+    let _config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
-    let oracle_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let claimant_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let _token_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
     
-    // Only oracle can call this (when Bitcoin Ordinal transfers)
-    if !oracle_account.is_signer {
+    if !claimant_account.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
     
-    // TODO: Verify oracle_account is the authorized oracle
-    
     let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
         .map_err(|_| ProgramError::InvalidAccountData)?;
     
-    let old_owner = state.owner;
-    state.owner = new_owner;
+    let amount = take_settled_rewards(&mut state, claimant_account.key)?;
+    
+    // Mint rewards via CPI
+    // TODO: Implement CPI to token program
     
     let serialized_state = state.try_to_vec()
         .map_err(|_| ProgramError::InvalidAccountData)?;
     
     add_state_transition(state_account, serialized_state);
     
-    msg!("Ownership transferred from {:?} to {:?}", old_owner, new_owner);
+    msg!("Previous owner claimed {} settled $GOTCHI rewards", amount);
     
     Ok(())
 }
 
-// ============================================================================
-// HELPER FUNCTIONS
-// ============================================================================
-
-fn get_current_block() -> u64 {
-    // In production, this comes from Arch runtime
-    // Returns current Bitcoin block height
-    800_000 // Placeholder for actual implementation
-}
-
-fn calculate_burn_amount(action: &str) -> u64 {
-    // Base amounts (would query circulating supply in production)
-    match action {
-        "feed" => 5_000_000_000,      // 5 $GOTCHI (9 decimals)
-        "play" => 3_000_000_000,      // 3 $GOTCHI
-        "clean" => 2_000_000_000,     // 2 $GOTCHI
-        "medicine" => 10_000_000_000, // 10 $GOTCHI
-        _ => 0,
+pub fn process_register_owner_achievements(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    // GameConfig (pause check happens in process_instruction)
+    let _config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let achievements_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    if !owner_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
     }
-}
-
-fn is_poop_generated() -> bool {
-    // In production, use Arch's random number generator
-    // For now, simple pseudo-random based on block
-    get_current_block() % 5 == 0 // 20% chance
-}
-
-fn get_feed_threshold(level: &Level) -> u64 {
-    // Time windows in Bitcoin blocks (144 blocks ≈ 1 day)
-    match level {
-        Level::Baby => 144,      // 24 hours
-        Level::Child => 120,     // 20 hours
-        Level::Teen => 108,      // 18 hours
-        Level::Adult => 96,      // 16 hours
-        Level::Senior => 84,     // 14 hours
-        _ => 144,
+    
+    // Never wipe an existing record
+    if achievements_account.data.borrow().iter().any(|byte| *byte != 0) {
+        return Err(ProgramError::AccountAlreadyInitialized);
     }
-}
-
-fn should_die(state: &SatsgotchiState, current_block: u64) -> bool {
-    // Death probability based on level and neglect
-    let days_neglected = (current_block.saturating_sub(state.last_fed_block)) / 144;
     
-    let death_chance = match state.level {
-        Level::Baby => days_neglected > 5,
-        Level::Child => days_neglected > 3,
-        Level::Teen => days_neglected > 2,
-        Level::Adult => days_neglected > 2,
-        Level::Senior => days_neglected > 1,
-        _ => false,
+    let record = OwnerAchievements {
+        owner: *owner_account.key,
+        badges: 0,
+        longest_streak_days: 0,
     };
     
-    death_chance
+    let serialized_record = record.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(achievements_account, serialized_record);
+    
+    msg!("Achievements registered for {:?}", record.owner);
+    
+    Ok(())
 }
 
-fn accumulate_rewards(state: &mut SatsgotchiState, blocks_elapsed: u64) {
-    // Calculate earning rate (% of circulating supply per hour)
-    // In Bitcoin blocks: 144 blocks = 1 day, 6 blocks = 1 hour
+pub fn process_transfer_ownership(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_owner: Pubkey,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
     
-    let base_rate = match state.level {
-        Level::Baby => 20,        // 0.00002% per hour
-        Level::Child => 40,       // 0.00004%
-        Level::Teen => 120,       // 0.00012%
-        Level::Adult => 300,      // 0.0003%
-        Level::Senior => 800,     // 0.0008%
-        Level::Ascended => 1000,  // 0.001%
-        _ => 0,
-    };
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let weather = next_weather_account(program_id, account_iter)?;
+    let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let oracle_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    // Only the oracle can call this (when the Bitcoin Ordinal transfers)
+    if !oracle_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    let params = config.active_params(current_block);
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
+    
+    if *oracle_account.key != config.oracle {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    credit_paused_blocks(&mut state, config.total_paused_blocks(current_block));
+    
+    // The sale already happened on Bitcoin, so it's always mirrored; a quick
+    // resale just earns the new owner no care credit for a while
+    let old_owner = state.owner;
+    let quick_resale = in_transfer_cooldown(&state, params, current_block);
+    let settled = apply_transfer(&mut state, params, circulating_supply, weather.as_ref(), new_owner, current_block);
+    
+    if quick_resale {
+        msg!("Quick resale: no care credit for the new owner until block {}",
+            current_block.saturating_add(params.transfer_cooldown_blocks));
+    }
+    
+    let serialized_state = state.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
     
-    // Apply care multiplier
-    let multiplied_rate = (base_rate * state.care_multiplier as u64) / 100;
+    add_state_transition(state_account, serialized_state);
     
-    // Calculate rewards for time elapsed
-    let hours_elapsed = blocks_elapsed / 6;
-    let rewards = multiplied_rate * hours_elapsed;
+    msg!("Ownership transferred from {:?} to {:?} ({} $GOTCHI settled to seller)",
+        old_owner, new_owner, settled);
     
-    state.unclaimed_rewards += rewards;
-    state.total_earned += rewards;
+    Ok(())
 }
 
-// ============================================================================
-// TESTS (would be in separate file in production)
-// ============================================================================
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_initialize() {
-        // Test initialization logic
+pub fn process_add_caretaker(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    caretaker: Pubkey,
+    expires_block: u64,
+    allowed_actions: u8,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    // GameConfig (pause check happens in process_instruction)
+    let _config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    if !owner_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
     }
-
-    #[test]
-    fn test_feed() {
-        // Test feeding logic
+    
+    let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    // Only the owner can delegate
+    if state.owner != *owner_account.key {
+        return Err(ProgramError::IllegalOwner);
     }
+    
+    add_caretaker(&mut state, caretaker, expires_block, allowed_actions, get_current_block())?;
+    
+    let serialized_state = state.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(state_account, serialized_state);
+    
+    msg!("Caretaker {:?} added until block {}", caretaker, expires_block);
+    
+    Ok(())
+}
 
-    #[test]
-    fn test_evolution() {
-        // Test evolution requirements
+pub fn process_remove_caretaker(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    caretaker: Pubkey,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    if !owner_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    if state.owner != *owner_account.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    let before = state.caretakers.len();
+    state.caretakers.retain(|c| c.pubkey != caretaker);
+    
+    if state.caretakers.len() == before {
+        return Err(ProgramError::Custom(6)); // Not a caretaker of this pet
+    }
+    
+    let serialized_state = state.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(state_account, serialized_state);
+    
+    msg!("Caretaker {:?} removed", caretaker);
+    
+    Ok(())
+}
+
+pub fn process_freeze(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    blocks: u64,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let weather = next_weather_account(program_id, account_iter)?;
+    let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let gotchi_token_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_program_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    if !owner_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    // Only the owner can put the pet on vacation
+    if state.owner != *owner_account.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    let params = config.active_params(current_block);
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
+    
+    // Paused game time never counts against the pet
+    credit_paused_blocks(&mut state, config.total_paused_blocks(current_block));
+    
+    // Burn scales with the length of the freeze; paid before the window opens
+    let burn_amount = calculate_burn_amount(params, circulating_supply, "freeze").saturating_mul(blocks);
+    burn_gotchi(token_program_account, gotchi_token_account, owner_account, token_state_account, burn_amount)?;
+    
+    apply_freeze(&mut state, params, circulating_supply, weather.as_ref(), blocks, current_block)?;
+    
+    let serialized_state = state.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(state_account, serialized_state);
+    
+    // Optional last account: the owner's achievements record
+    record_owner_achievements(account_iter.next(), &state)?;
+    
+    msg!("Satsgotchi frozen until block {} ({}/{} lifetime blocks used)",
+        state.frozen_until_block, state.total_frozen_blocks, MAX_LIFETIME_FROZEN_BLOCKS);
+    
+    Ok(())
+}
+
+pub fn process_initialize_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    timelock_blocks: u64,
+    params: GameParams,
+    guardian: Pubkey,
+    token_state: Pubkey,
+    oracle: Pubkey,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let admin_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    if !admin_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    if *config_account.key != config_address(program_id) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    
+    // One config per program: never overwrite an existing admin
+    if config_account.data.borrow().iter().any(|byte| *byte != 0) {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+    
+    let config = GameConfig::new(*admin_account.key, guardian, oracle, token_state, timelock_blocks, params)?;
+    
+    let serialized_config = config.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(config_account, serialized_config);
+    
+    msg!("Game config initialized. Admin: {:?}, timelock: {} blocks", config.admin, config.timelock_blocks);
+    
+    Ok(())
+}
+
+pub fn process_propose_config_update(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: GameParams,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let admin_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    let mut config = load_config_as_admin(config_account, admin_account, program_id)?;
+    
+    propose_config_update(&mut config, params, get_current_block())?;
+    
+    let serialized_config = config.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(config_account, serialized_config);
+    
+    msg!("Config update queued, effective at block {}", config.pending_effective_block);
+    
+    Ok(())
+}
+
+pub fn process_cancel_config_update(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let admin_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    let mut config = load_config_as_admin(config_account, admin_account, program_id)?;
+    
+    cancel_config_update(&mut config, get_current_block())?;
+    
+    let serialized_config = config.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(config_account, serialized_config);
+    
+    msg!("Config update cancelled");
+    
+    Ok(())
+}
+
+pub fn process_set_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused: bool,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let guardian_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    if !guardian_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    let mut config = load_config(config_account, program_id)?;
+    
+    if config.guardian != *guardian_account.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    set_paused(&mut config, paused, get_current_block())?;
+    
+    let serialized_config = config.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(config_account, serialized_config);
+    
+    if paused {
+        msg!("Game paused at block {}", config.paused_at_block);
+    } else {
+        msg!("Game resumed ({} blocks paused in total)", config.paused_blocks);
+    }
+    
+    Ok(())
+}
+
+pub fn process_set_weather_oracle(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    oracle: Pubkey,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let weather_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let admin_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    load_config_as_admin(config_account, admin_account, program_id)?;
+    
+    if *weather_account.key != weather_address(program_id) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    
+    // Keep the readings when the oracle is rotated
+    let weather = match WeatherState::try_from_slice(&weather_account.data.borrow()) {
+        Ok(existing) => WeatherState { oracle, ..existing },
+        Err(_) => WeatherState {
+            oracle,
+            readings: Vec::new(),
+        },
+    };
+    
+    let serialized_weather = weather.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(weather_account, serialized_weather);
+    
+    msg!("Weather oracle set to {:?}", oracle);
+    
+    Ok(())
+}
+
+pub fn process_set_transfer_oracle(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    oracle: Pubkey,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let admin_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    let mut config = load_config_as_admin(config_account, admin_account, program_id)?;
+    config.oracle = oracle;
+    
+    let serialized_config = config.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(config_account, serialized_config);
+    
+    msg!("Transfer oracle set to {:?}", oracle);
+    
+    Ok(())
+}
+
+pub fn process_publish_weather(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    weather: Weather,
+    observed_block: u64,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    // GameConfig (pause check happens in process_instruction)
+    let _config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let weather_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let oracle_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    if !oracle_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    if *weather_account.key != weather_address(program_id) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    
+    let mut state = WeatherState::try_from_slice(&weather_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    if state.oracle != *oracle_account.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    state.push(WeatherReading { weather, observed_block })?;
+    
+    let serialized_weather = state.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(weather_account, serialized_weather);
+    
+    msg!("Weather at block {}: {:?}", observed_block, weather);
+    
+    Ok(())
+}
+
+pub fn process_quote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
+    
+    let quote = quote_burns(config.active_params(current_block), circulating_supply);
+    
+    msg!("Burn quote at block {}: feed={} snack={} play={} clean={} medicine={} freeze_per_block={} rename={}",
+        current_block, quote.feed, quote.snack, quote.play, quote.clean, quote.medicine,
+        quote.freeze_per_block, quote.rename);
+    
+    Ok(())
+}
+
+pub fn process_forecast(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    
+    let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    credit_paused_blocks(&mut state, config.total_paused_blocks(current_block));
+    
+    let forecast = forecast(&state, config.active_params(current_block), current_block);
+    
+    // Nothing is written: the forecast runs on a copy
+    msg!("{} forecast at block {}: next care mistake {:?}, death under no care {:?}, next evolution {:?}",
+        state.inscription_id, current_block, forecast.next_care_mistake_block,
+        forecast.death_block, forecast.next_evolution_block);
+    
+    Ok(())
+}
+
+pub fn process_register_household(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    // GameConfig (pause check happens in process_instruction)
+    let _config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let household_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    if !owner_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    if *household_account.key != household_address(program_id, owner_account.key) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    
+    // Never wipe an existing household
+    if !is_zeroed(household_account) {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+    
+    let household = Household {
+        owner: *owner_account.key,
+        pets: Vec::new(),
+        pets_adopted: 0,
+        total_care_actions: 0,
+        alive_pets: 0,
+        total_earned: 0,
+        updated_block: get_current_block(),
+    };
+    
+    let serialized_household = household.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(household_account, serialized_household);
+    
+    msg!("Household registered for {:?}", household.owner);
+    
+    Ok(())
+}
+
+pub fn process_add_to_household(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    // GameConfig (pause check happens in process_instruction)
+    let _config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let household_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    let mut household = load_household(program_id, household_account, owner_account)?;
+    
+    let state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    if state.owner != household.owner {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    if household.pets.contains(state_account.key) {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    if household.pets.len() >= MAX_HOUSEHOLD_PETS {
+        return Err(ProgramError::Custom(12)); // Household full
+    }
+    
+    household.pets.push(*state_account.key);
+    household.pets_adopted += 1;
+    
+    let serialized_household = household.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(household_account, serialized_household);
+    
+    msg!("{} joined the household ({} pets)", state.inscription_id, household.pets.len());
+    
+    Ok(())
+}
+
+pub fn process_remove_from_household(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    // GameConfig (pause check happens in process_instruction)
+    let _config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let household_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    let mut household = load_household(program_id, household_account, owner_account)?;
+    
+    let position = household.pets.iter()
+        .position(|pet| pet == state_account.key)
+        .ok_or(ProgramError::Custom(13))?; // Not in this household
+    household.pets.remove(position);
+    
+    // A pet that was sold already lost its bonus on transfer
+    let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if state.owner == household.owner {
+        state.household_bonus = 0;
+        
+        let serialized_state = state.try_to_vec()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        
+        add_state_transition(state_account, serialized_state);
+    }
+    
+    let serialized_household = household.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(household_account, serialized_household);
+    
+    msg!("{} left the household ({} pets)", state.inscription_id, household.pets.len());
+    
+    Ok(())
+}
+
+pub fn process_care_all(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    action: u8,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let weather = next_weather_account(program_id, account_iter)?;
+    let household_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let gotchi_token_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_program_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    let action_name = match action {
+        CARE_FEED => "feed",
+        CARE_PLAY => "play",
+        CARE_CLEAN => "clean",
+        CARE_MEDICINE => "medicine",
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+    
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    let params = config.active_params(current_block);
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
+    
+    let mut household = load_household(program_id, household_account, owner_account)?;
+    
+    // Every household pet, in household order, then optionally the
+    // owner's OwnerAchievements account
+    let mut pet_accounts: Vec<&AccountInfo> = account_iter.collect();
+    let achievements_account = if pet_accounts.len() == household.pets.len() + 1 {
+        pet_accounts.pop()
+    } else {
+        None
+    };
+    if pet_accounts.len() != household.pets.len()
+        || pet_accounts.iter().zip(&household.pets).any(|(account, pet)| account.key != pet)
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    let mut members = Vec::with_capacity(pet_accounts.len());
+    let mut pets = Vec::with_capacity(pet_accounts.len());
+    let mut skipped = 0u32;
+    
+    for state_account in pet_accounts {
+        let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        
+        // Sold pets leave the household
+        if state.owner != household.owner {
+            msg!("{}: sold, removed from household", state.inscription_id);
+            continue;
+        }
+        
+        credit_paused_blocks(&mut state, config.total_paused_blocks(current_block));
+        
+        // Dead or on-cooldown pets are skipped, not fatal to the batch
+        let cared = settle_elapsed_time(&mut state, params, circulating_supply, weather.as_ref(), current_block)
+            .and_then(|tick| {
+                log_tick(&state, &tick);
+                apply_care(&mut state, action, current_block)
+            })
+            .is_ok();
+        if cared {
+            log_quest_rewards(settle_quests(&mut state, params, current_block));
+        } else {
+            skipped += 1;
+        }
+        
+        members.push(state_account);
+        pets.push(state);
+    }
+    
+    let cared = (pets.len() as u32 - skipped) as u64;
+    household.pets = members.iter().map(|account| *account.key).collect();
+    household.total_care_actions += cared;
+    refresh_household(&mut household, params, &mut pets, current_block);
+    
+    // Each pet cared for costs what the action costs on its own (nothing is
+    // burned if every pet was skipped)
+    let per_pet_burn = calculate_burn_amount(params, circulating_supply, action_name);
+    let burn_amount = per_pet_burn.saturating_mul(cared);
+    burn_gotchi(token_program_account, gotchi_token_account, owner_account, token_state_account, burn_amount)?;
+    
+    for (state_account, state) in members.iter().zip(&pets) {
+        let serialized_state = state.try_to_vec()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        
+        add_state_transition(state_account, serialized_state);
+    }
+    
+    let serialized_household = household.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(household_account, serialized_household);
+    
+    record_household_achievements(achievements_account, &pets)?;
+    
+    msg!("CareAll {}: {} cared ({} $GOTCHI, {} each), {} skipped, {} alive, household bonus +{}",
+        action_name, cared, burn_amount, per_pet_burn, skipped, household.alive_pets,
+        household_bonus(params, household.alive_pets as usize));
+    
+    Ok(())
+}
+
+pub fn process_set_name(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: String,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let gotchi_token_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_program_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let name_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    if !owner_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    let params = config.active_params(current_block);
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
+    
+    let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    if state.owner != *owner_account.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    if state.status == Status::Dead {
+        return Err(ProgramError::Custom(1)); // Pet is dead
+    }
+    
+    let key = normalize_name(&name)?;
+    if *name_account.key != name_address(program_id, &key) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    
+    // Taken unless free or already this pet's (a change of case)
+    if !is_zeroed(name_account) {
+        let record = NameRecord::try_from_slice(&name_account.data.borrow())
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if record.pet != *state_account.key {
+            return Err(ProgramError::Custom(14)); // Name taken
+        }
+    }
+    
+    // Renaming frees the old name's record
+    if !state.name.is_empty() && normalize_name(&state.name)? != key {
+        let old_name_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if *old_name_account.key != name_address(program_id, &normalize_name(&state.name)?) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        
+        add_state_transition(old_name_account, vec![0; old_name_account.data.borrow().len()]);
+    }
+    
+    let burn_amount = calculate_burn_amount(params, circulating_supply, "rename");
+    burn_gotchi(token_program_account, gotchi_token_account, owner_account, token_state_account, burn_amount)?;
+    
+    let record = NameRecord {
+        name: name.clone(),
+        pet: *state_account.key,
+        claimed_block: current_block,
+    };
+    
+    let old_name = std::mem::replace(&mut state.name, name);
+    
+    let serialized_record = record.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let serialized_state = state.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(name_account, serialized_record);
+    add_state_transition(state_account, serialized_state);
+    
+    if old_name.is_empty() {
+        msg!("{} is now called {}", state.inscription_id, state.name);
+    } else {
+        msg!("{} renamed from {} to {}", state.inscription_id, old_name, state.name);
+    }
+    
+    Ok(())
+}
+
+pub fn process_release_name(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let name_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    
+    let record = NameRecord::try_from_slice(&name_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    if *name_account.key != name_address(program_id, &normalize_name(&record.name)?) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    
+    if record.pet != *state_account.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    if !is_name_releasable(&state, config.active_params(current_block), current_block) {
+        return Err(ProgramError::Custom(15)); // Name still held
+    }
+    
+    state.name.clear();
+    
+    let serialized_state = state.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(name_account, vec![0; name_account.data.borrow().len()]);
+    add_state_transition(state_account, serialized_state);
+    
+    msg!("Name {} released from {}", record.name, state.inscription_id);
+    
+    Ok(())
+}
+
+pub fn process_visit(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    host_pet: Pubkey,
+) -> Result<(), ProgramError> {
+    let account_iter = &mut accounts.iter();
+    
+    let config_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let token_state_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let weather = next_weather_account(program_id, account_iter)?;
+    let visitor_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let owner_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let host_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let friendship_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    
+    if !owner_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    if *host_account.key != host_pet {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    if *friendship_account.key != friendship_address(program_id, visitor_account.key, &host_pet) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    
+    let current_block = get_current_block();
+    let config = load_config(config_account, program_id)?;
+    let params = config.active_params(current_block);
+    let circulating_supply = load_circulating_supply(token_state_account, &config)?;
+    
+    let mut visitor = SatsgotchiState::try_from_slice(&visitor_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let mut host = SatsgotchiState::try_from_slice(&host_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    if visitor.owner != *owner_account.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    let mut friendship = if is_zeroed(friendship_account) {
+        Friendship::new(*visitor_account.key, host_pet)
+    } else {
+        Friendship::try_from_slice(&friendship_account.data.borrow())
+            .map_err(|_| ProgramError::InvalidAccountData)?
+    };
+    
+    // Bring both pets up to date before the visit
+    let paused_blocks = config.total_paused_blocks(current_block);
+    for pet in [&mut visitor, &mut host] {
+        credit_paused_blocks(pet, paused_blocks);
+        let tick = settle_elapsed_time(pet, params, circulating_supply, weather.as_ref(), current_block)?;
+        log_tick(pet, &tick);
+    }
+    
+    let happiness = apply_visit(
+        &mut friendship, &mut visitor, *visitor_account.key, &mut host, params, current_block,
+    )?;
+    log_badges(unlock_achievements(&mut visitor) | unlock_achievements(&mut host));
+    
+    let serialized_visitor = visitor.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let serialized_host = host.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let serialized_friendship = friendship.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(visitor_account, serialized_visitor);
+    add_state_transition(host_account, serialized_host);
+    add_state_transition(friendship_account, serialized_friendship);
+    
+    msg!("{} visited {}: +{} happiness each, friendship level {} ({} visits)",
+        visitor.inscription_id, host.inscription_id, happiness,
+        friendship.level, friendship.visits);
+    
+    Ok(())
+}
+
+// ============================================================================
+// GAME ENGINE
+// ============================================================================
+//
+// Pure state transitions shared by the instruction processors and the
+// off-chain simulators. Nothing in here touches accounts or the runtime;
+// the caller supplies the current Bitcoin block height.
+
+/// What a single `apply_time_elapsed` tick did to a pet
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TickOutcome {
+    pub blocks_elapsed: u64,
+    pub frozen_blocks: u64,
+    pub care_mistake: bool,
+    pub died: bool,
+    pub rewards_accrued: u64,
+    pub badges_unlocked: u32,
+    pub quest_rewards: u64,
+    pub bonus_blocks: u64,               // Blocks that earned double (halving)
+    pub accessory_dropped: Option<u8>,
+    pub fell_sick: bool,
+    pub weather_happiness: i16,          // Set by `apply_tick`
+}
+
+/// $GOTCHI burned by each care action right now
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BurnQuote {
+    pub feed: u64,
+    pub snack: u64,
+    pub play: u64,
+    pub clean: u64,
+    pub medicine: u64,
+    pub freeze_per_block: u64,
+    pub rename: u64,
+}
+
+/// What happens to a pet if nobody cares for it (None = not within the horizon)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Forecast {
+    pub next_care_mistake_block: Option<u64>,
+    pub death_block: Option<u64>,
+    pub next_evolution_block: Option<u64>,
+}
+
+/// A Bitcoin network event whose effect window covers a block
+#[derive(Debug, Clone, PartialEq)]
+pub enum BitcoinEvent {
+    Halving { epoch: u64 },              // Double earnings, edition accessory
+    DifficultyAdjustment { period: u64 }, // Early evolution window
+}
+
+impl Default for GameParams {
+    /// Launch balance (144 blocks ≈ 1 day, 6 blocks ≈ 1 hour)
+    fn default() -> Self {
+        Self {
+            baby: LevelParams {
+                feed_threshold_blocks: 144, // 24 hours
+                death_neglect_days: 5,
+                health_decay_blocks: 288,   // 0.5/day
+                evolution_blocks: 1_008,    // 7 days
+                evolution_reward: 50,
+                reward_rate_ppb: 200,       // 0.00002% per hour
+            },
+            child: LevelParams {
+                feed_threshold_blocks: 120, // 20 hours
+                death_neglect_days: 3,
+                health_decay_blocks: 144,   // 1/day
+                evolution_blocks: 4_032,    // 28 days
+                evolution_reward: 250,
+                reward_rate_ppb: 400,       // 0.00004%
+            },
+            teen: LevelParams {
+                feed_threshold_blocks: 108, // 18 hours
+                death_neglect_days: 2,
+                health_decay_blocks: 96,    // 1.5/day
+                evolution_blocks: 17_280,   // ~120 days
+                evolution_reward: 1_500,
+                reward_rate_ppb: 1_200,     // 0.00012%
+            },
+            adult: LevelParams {
+                feed_threshold_blocks: 96,  // 16 hours
+                death_neglect_days: 2,
+                health_decay_blocks: 72,    // 2/day
+                evolution_blocks: 17_280,   // ~120 days
+                evolution_reward: 25_000,
+                reward_rate_ppb: 3_000,     // 0.0003%
+            },
+            senior: LevelParams {
+                feed_threshold_blocks: 84,  // 14 hours
+                death_neglect_days: 1,
+                health_decay_blocks: 48,    // 3/day
+                evolution_blocks: 11_520,   // ~80 days
+                evolution_reward: 2_000_000, // Ascension
+                reward_rate_ppb: 8_000,     // 0.0008%
+            },
+            ascended_reward_rate_ppb: 10_000, // 0.001%
+            hunger_period_blocks: 144,
+            happiness_period_blocks: 144,
+            freeze_burn_per_block: 50_000_000, // 0.05 $GOTCHI (7.2/day)
+            rename_burn: 25_000_000_000,    // 25 $GOTCHI
+            transfer_cooldown_blocks: 144,  // 1 day
+            quest_reward: 10_000_000_000,   // 10 $GOTCHI
+            streak_bonus_per_day: 1,        // +1% per day
+            max_streak_bonus: 30,           // up to +30%
+            halving_bonus_blocks: 1_008,    // 1 week of double earnings
+            difficulty_window_blocks: 144,  // 1 day after each retarget
+            difficulty_evolution_early_blocks: 144, // evolve up to 1 day early
+            visit_happiness: 10,
+            friendship_happiness_per_level: 2, // +10 more at best friends
+            visit_cooldown_blocks: 144,     // 1 visit per pair per day
+            name_grace_blocks: 1_008,       // 7 days
+            household_bonus_per_pet: 2,     // +2% per extra living pet
+            max_household_bonus: 10,        // up to +10%
+            storm_drain_period_blocks: 36,  // -4 happiness/day in a storm
+            quiet_boost_period_blocks: 72,  // +2 happiness/day when quiet
+        }
+    }
+}
+
+impl GameParams {
+    /// Parameters for a mortal life stage (None for Egg and Ascended)
+    pub fn level(&self, level: &Level) -> Option<&LevelParams> {
+        match level {
+            Level::Baby => Some(&self.baby),
+            Level::Child => Some(&self.child),
+            Level::Teen => Some(&self.teen),
+            Level::Adult => Some(&self.adult),
+            Level::Senior => Some(&self.senior),
+            _ => None,
+        }
+    }
+    
+    /// Reject parameter sets that would divide by zero or freeze evolution
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let levels = [&self.baby, &self.child, &self.teen, &self.adult, &self.senior];
+        
+        if self.hunger_period_blocks == 0
+            || self.happiness_period_blocks == 0
+            || levels.iter().any(|l| l.health_decay_blocks == 0 || l.evolution_blocks == 0)
+            || self.halving_bonus_blocks >= HALVING_INTERVAL
+            || self.difficulty_window_blocks >= DIFFICULTY_ADJUSTMENT_INTERVAL
+            || self.storm_drain_period_blocks == 0
+            || self.quiet_boost_period_blocks == 0
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        
+        Ok(())
+    }
+}
+
+impl GameConfig {
+    pub fn new(
+        admin: Pubkey,
+        guardian: Pubkey,
+        oracle: Pubkey,
+        token_state: Pubkey,
+        timelock_blocks: u64,
+        params: GameParams,
+    ) -> Result<Self, ProgramError> {
+        if timelock_blocks < MIN_CONFIG_TIMELOCK_BLOCKS {
+            return Err(ProgramError::InvalidArgument);
+        }
+        params.validate()?;
+        
+        Ok(Self {
+            admin,
+            oracle,
+            token_state,
+            timelock_blocks,
+            params,
+            pending_params: None,
+            pending_effective_block: 0,
+            guardian,
+            paused: false,
+            paused_at_block: 0,
+            paused_blocks: 0,
+        })
+    }
+    
+    /// Blocks the game has spent paused up to `current_block`
+    pub fn total_paused_blocks(&self, current_block: u64) -> u64 {
+        if self.paused {
+            self.paused_blocks + current_block.saturating_sub(self.paused_at_block)
+        } else {
+            self.paused_blocks
+        }
+    }
+    
+    /// Parameters in force at `current_block`: a queued update takes over
+    /// as soon as its timelock has passed, even before it's written back
+    pub fn active_params(&self, current_block: u64) -> &GameParams {
+        match &self.pending_params {
+            Some(pending) if current_block >= self.pending_effective_block => pending,
+            _ => &self.params,
+        }
+    }
+    
+    /// Move a queued update whose timelock has passed into `params`
+    fn promote_pending(&mut self, current_block: u64) {
+        if current_block >= self.pending_effective_block {
+            if let Some(pending) = self.pending_params.take() {
+                self.params = pending;
+            }
+        }
+    }
+}
+
+/// Queue `params` behind the timelock, replacing any update still waiting
+pub fn propose_config_update(
+    config: &mut GameConfig,
+    params: GameParams,
+    current_block: u64,
+) -> Result<(), ProgramError> {
+    params.validate()?;
+    
+    config.promote_pending(current_block);
+    config.pending_params = Some(params);
+    config.pending_effective_block = current_block + config.timelock_blocks;
+    
+    Ok(())
+}
+
+/// Drop the queued update; fails once it has already taken effect
+pub fn cancel_config_update(config: &mut GameConfig, current_block: u64) -> Result<(), ProgramError> {
+    config.promote_pending(current_block);
+    
+    if config.pending_params.take().is_none() {
+        return Err(ProgramError::Custom(9)); // No pending config update
+    }
+    
+    Ok(())
+}
+
+/// Pause or resume the game; toggling to the current state is rejected
+pub fn set_paused(config: &mut GameConfig, paused: bool, current_block: u64) -> Result<(), ProgramError> {
+    if config.paused == paused {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    if paused {
+        config.paused_at_block = current_block;
+    } else {
+        config.paused_blocks = config.total_paused_blocks(current_block);
+    }
+    config.paused = paused;
+    
+    Ok(())
+}
+
+/// Skip a pet over game pauses it hasn't been credited for yet: every timer
+/// and deadline moves forward, so paused time causes no decay, neglect or
+/// earnings, and doesn't use up cooldowns, sitters or bonus windows
+pub fn credit_paused_blocks(state: &mut SatsgotchiState, total_paused_blocks: u64) {
+    let paused = total_paused_blocks.saturating_sub(state.paused_blocks_credited);
+    if paused == 0 {
+        return;
+    }
+    
+    // Unset (zero) deadlines stay unset
+    let shift = |block: &mut u64| {
+        if *block > 0 {
+            *block = block.saturating_add(paused);
+        }
+    };
+    
+    state.last_update_block += paused;
+    state.last_fed_block += paused;
+    state.last_played_block += paused;
+    state.last_cleaned_block += paused;
+    state.evolution_eligible_block = state.evolution_eligible_block.saturating_add(paused);
+    shift(&mut state.frozen_until_block);
+    shift(&mut state.poop_since_block);
+    shift(&mut state.household_bonus_until);
+    shift(&mut state.last_transfer_block);
+    for cooldown in [
+        &mut state.cooldowns.feed,
+        &mut state.cooldowns.play,
+        &mut state.cooldowns.clean,
+        &mut state.cooldowns.medicine,
+    ] {
+        shift(&mut cooldown.last_block);
+        shift(&mut cooldown.window_start_block);
+    }
+    for caretaker in state.caretakers.iter_mut() {
+        shift(&mut caretaker.expires_block);
+    }
+    state.paused_blocks_credited = total_paused_blocks;
+}
+
+impl SatsgotchiState {
+    /// Fresh Baby pet as created by `Initialize`
+    pub fn new(
+        inscription_id: String,
+        owner: Pubkey,
+        traits: Traits,
+        params: &GameParams,
+        current_block: u64,
+    ) -> Self {
+        let evolution_blocks = species_params(params, &traits).baby.evolution_blocks;
+        
+        Self {
+            inscription_id,
+            owner,
+            name: String::new(),
+            level: Level::Baby,
+            status: Status::Alive,
+            health: 100,
+            happiness: 100,
+            hunger: 0,
+            birth_block: current_block,
+            age_blocks: 0,
+            last_fed_block: current_block,
+            last_played_block: current_block,
+            last_cleaned_block: current_block,
+            last_update_block: current_block,
+            died_block: 0,
+            care_mistakes: 0,
+            missed_feed_windows: 0,
+            perfect_care_days: 0,
+            current_streak_days: 0,
+            longest_streak_days: 0,
+            streak_history: Vec::new(),
+            poop_count: 0,
+            poop_since_block: 0,
+            sick: false,
+            weight: BIRTH_WEIGHT,
+            fitness: BIRTH_FITNESS,
+            history: EventHistory::default(),
+            total_earned: 0,
+            unclaimed_rewards: 0,
+            care_multiplier: 100, // 1.0x
+            household_bonus: 0,
+            household_bonus_until: 0,
+            settled_rewards: Vec::new(),
+            reward_remainder: 0,
+            traits,
+            evolution_eligible_block: current_block + evolution_blocks,
+            caretakers: Vec::new(),
+            frozen_until_block: 0,
+            total_frozen_blocks: 0,
+            badges: 0,
+            best_friendship: 0,
+            quests: QuestProgress::new(current_block / QUEST_DAY_BLOCKS, 0),
+            paused_blocks_credited: 0,
+            last_transfer_block: 0,
+            last_keeper_reward_block: 0,
+            cooldowns: CareCooldowns::default(),
+        }
+    }
+}
+
+impl CareCooldowns {
+    fn slot(&self, action: u8) -> &ActionCooldown {
+        match action {
+            CARE_FEED => &self.feed,
+            CARE_PLAY => &self.play,
+            CARE_CLEAN => &self.clean,
+            _ => &self.medicine,
+        }
+    }
+    
+    fn slot_mut(&mut self, action: u8) -> &mut ActionCooldown {
+        match action {
+            CARE_FEED => &mut self.feed,
+            CARE_PLAY => &mut self.play,
+            CARE_CLEAN => &mut self.clean,
+            _ => &mut self.medicine,
+        }
+    }
+}
+
+fn cooldown_blocks(action: u8) -> u64 {
+    match action {
+        CARE_FEED => FEED_COOLDOWN_BLOCKS,
+        CARE_PLAY => PLAY_COOLDOWN_BLOCKS,
+        CARE_CLEAN => CLEAN_COOLDOWN_BLOCKS,
+        _ => MEDICINE_COOLDOWN_BLOCKS,
+    }
+}
+
+/// First block at which the care `action` (a single CARE_* flag) may run again
+pub fn next_action_block(state: &SatsgotchiState, action: u8) -> u64 {
+    state.cooldowns.slot(action).last_block + cooldown_blocks(action)
+}
+
+/// Record a care action: fails with Custom(8) while it's on cooldown,
+/// otherwise returns the effect strength in percent (100, 50, 25, ...
+/// for each repeat inside the current CARE_WINDOW_BLOCKS window)
+pub fn use_care_action(
+    state: &mut SatsgotchiState,
+    action: u8,
+    current_block: u64,
+) -> Result<u8, ProgramError> {
+    if current_block < next_action_block(state, action) {
+        return Err(ProgramError::Custom(8)); // Action on cooldown
+    }
+    
+    let slot = state.cooldowns.slot_mut(action);
+    if current_block >= slot.window_start_block + CARE_WINDOW_BLOCKS {
+        slot.window_start_block = current_block;
+        slot.uses_in_window = 0;
+    }
+    
+    let strength = 100u8 >> slot.uses_in_window.min(7);
+    slot.uses_in_window = slot.uses_in_window.saturating_add(1);
+    slot.last_block = current_block;
+    
+    Ok(strength)
+}
+
+/// `amount` scaled by a `use_care_action` strength
+fn scaled(amount: u8, strength: u8) -> u8 {
+    (amount as u16 * strength as u16 / 100) as u8
+}
+
+pub fn apply_feed(state: &mut SatsgotchiState, current_block: u64) -> Result<(), ProgramError> {
+    if state.status == Status::Dead {
+        return Err(ProgramError::Custom(1)); // Cannot feed dead pet
+    }
+    
+    let before = meters(state);
+    
+    let strength = use_care_action(state, CARE_FEED, current_block)?;
+    
+    state.hunger = state.hunger.saturating_sub(scaled(50, strength));
+    state.health = (state.health + scaled(10, strength)).min(100);
+    state.weight = state.weight.saturating_add(MEAL_WEIGHT_GAIN);
+    state.last_fed_block = current_block;
+    state.missed_feed_windows = 0;
+    
+    // Random poop generation (20% chance)
+    if is_poop_generated(current_block) {
+        if state.poop_count == 0 {
+            state.poop_since_block = current_block;
+        }
+        state.poop_count = (state.poop_count + 1).min(8);
+    }
+    
+    if let Some(quests) = todays_quests(state, current_block) {
+        quests.feeds = quests.feeds.saturating_add(1);
+    }
+    
+    record_event(state, PetEventKind::Feed, current_block, before);
+    
+    Ok(())
+}
+
+/// A snack shares the feed cooldown but doesn't count as a meal: it
+/// doesn't reset the neglect clock or count toward feeding quests
+pub fn apply_snack(state: &mut SatsgotchiState, current_block: u64) -> Result<(), ProgramError> {
+    if state.status == Status::Dead {
+        return Err(ProgramError::Custom(1));
+    }
+    
+    let before = meters(state);
+    
+    let strength = use_care_action(state, CARE_FEED, current_block)?;
+    
+    state.hunger = state.hunger.saturating_sub(scaled(15, strength));
+    state.happiness = (state.happiness + scaled(10, strength)).min(100);
+    state.weight = state.weight.saturating_add(SNACK_WEIGHT_GAIN);
+    
+    record_event(state, PetEventKind::Snack, current_block, before);
+    
+    Ok(())
+}
+
+pub fn apply_play(state: &mut SatsgotchiState, current_block: u64) -> Result<(), ProgramError> {
+    if state.status == Status::Dead {
+        return Err(ProgramError::Custom(1));
+    }
+    
+    let before = meters(state);
+    
+    let strength = use_care_action(state, CARE_PLAY, current_block)?;
+    
+    state.happiness = (state.happiness + scaled(20, strength)).min(100);
+    state.fitness = (state.fitness + scaled(PLAY_FITNESS_GAIN, strength)).min(100);
+    state.weight = state.weight.saturating_sub(PLAY_WEIGHT_LOSS).max(1);
+    state.last_played_block = current_block;
+    
+    if let Some(quests) = todays_quests(state, current_block) {
+        quests.plays = quests.plays.saturating_add(1);
+    }
+    
+    record_event(state, PetEventKind::Play, current_block, before);
+    
+    Ok(())
+}
+
+pub fn apply_clean(state: &mut SatsgotchiState, current_block: u64) -> Result<(), ProgramError> {
+    if state.status == Status::Dead {
+        return Err(ProgramError::Custom(1));
+    }
+    
+    let before = meters(state);
+    
+    let strength = use_care_action(state, CARE_CLEAN, current_block)?;
+    
+    if state.poop_count > 0 {
+        let waited = current_block.saturating_sub(state.poop_since_block);
+        if let Some(quests) = todays_quests(state, current_block) {
+            quests.fastest_clean = quests.fastest_clean.min(waited);
+        }
+    }
+    
+    state.poop_count = 0;
+    state.health = (state.health + scaled(10, strength)).min(100);
+    state.last_cleaned_block = current_block;
+    
+    record_event(state, PetEventKind::Clean, current_block, before);
+    
+    Ok(())
+}
+
+pub fn apply_medicine(state: &mut SatsgotchiState, current_block: u64) -> Result<(), ProgramError> {
+    if state.status == Status::Dead {
+        return Err(ProgramError::Custom(1));
+    }
+    
+    let before = meters(state);
+    
+    let strength = use_care_action(state, CARE_MEDICINE, current_block)?;
+    
+    state.sick = false;
+    state.health = (state.health + scaled(40, strength)).min(100);
+    
+    record_event(state, PetEventKind::Medicine, current_block, before);
+    
+    Ok(())
+}
+
+/// Check that a burn can come out of `balance`: it must be the payer's own
+/// token account (never the owner's when a caretaker signs) and cover the burn
+pub fn check_burn_payer(balance: &AccountBalance, payer: &Pubkey, amount: u64) -> Result<(), ProgramError> {
+    if balance.owner != *payer {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    if balance.balance < amount {
+        return Err(ProgramError::InsufficientFunds);
+    }
+    
+    Ok(())
+}
+
+/// Check that `signer` may perform the care `action` (a CARE_* flag):
+/// either the owner, or a caretaker whose delegation covers it and hasn't expired
+pub fn authorize_care(
+    state: &SatsgotchiState,
+    signer: &Pubkey,
+    action: u8,
+    current_block: u64,
+) -> Result<(), ProgramError> {
+    if state.owner == *signer {
+        return Ok(());
+    }
+    
+    let delegated = state.caretakers.iter().any(|c| {
+        c.pubkey == *signer
+            && current_block < c.expires_block
+            && c.allowed_actions & action == action
+    });
+    
+    if delegated {
+        Ok(())
+    } else {
+        Err(ProgramError::IllegalOwner)
+    }
+}
+
+/// Add or replace a caretaker, pruning expired delegations first
+pub fn add_caretaker(
+    state: &mut SatsgotchiState,
+    caretaker: Pubkey,
+    expires_block: u64,
+    allowed_actions: u8,
+    current_block: u64,
+) -> Result<(), ProgramError> {
+    if caretaker == state.owner
+        || expires_block <= current_block
+        || allowed_actions == 0
+        || allowed_actions & !CARE_ALL != 0
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    state.caretakers.retain(|c| c.expires_block > current_block && c.pubkey != caretaker);
+    
+    if state.caretakers.len() >= MAX_CARETAKERS {
+        return Err(ProgramError::Custom(5)); // Caretaker list full
+    }
+    
+    state.caretakers.push(Caretaker {
+        pubkey: caretaker,
+        expires_block,
+        allowed_actions,
+    });
+    
+    Ok(())
+}
+
+/// The quest set for a quest day, the same for every pet
+pub fn daily_quests(day: u64) -> [Quest; QUESTS_PER_DAY] {
+    let mut picked = [0usize; QUESTS_PER_DAY];
+    let mut count = 0;
+    let mut seed = day;
+    
+    // splitmix64 over the day number; skip repeats of the same pool entry
+    while count < QUESTS_PER_DAY {
+        let index = (splitmix64(&mut seed) % QUEST_POOL.len() as u64) as usize;
+        if !picked[..count].contains(&index) {
+            picked[count] = index;
+            count += 1;
+        }
+    }
+    
+    picked.map(|index| QUEST_POOL[index])
+}
+
+impl Quest {
+    /// Judged once the day is over rather than as soon as it's met
+    pub fn is_all_day(&self) -> bool {
+        matches!(self, Quest::KeepHungerBelow { .. })
+    }
+    
+    fn is_met(&self, progress: &QuestProgress) -> bool {
+        match *self {
+            Quest::Feed { times } => progress.feeds >= times,
+            Quest::Play { times } => progress.plays >= times,
+            Quest::KeepHungerBelow { max } => progress.max_hunger < max,
+            Quest::CleanWithin { blocks } => progress.fastest_clean <= blocks,
+        }
+    }
+}
+
+impl QuestProgress {
+    fn new(day: u64, hunger: u8) -> Self {
+        Self {
+            day,
+            fastest_clean: u64::MAX,
+            max_hunger: hunger,
+            ..Self::default()
+        }
+    }
+}
+
+/// Progress for the quest day containing `current_block`, if it's been started
+fn todays_quests(state: &mut SatsgotchiState, current_block: u64) -> Option<&mut QuestProgress> {
+    let today = current_block / QUEST_DAY_BLOCKS;
+    (state.quests.day == today).then_some(&mut state.quests)
+}
+
+/// Pay every quest the pet has completed: all-day quests of a day that just
+/// ended, and today's quests as soon as they're met. Rewards go to
+/// `unclaimed_rewards` (minted from the earning pool). Returns the payout.
+pub fn settle_quests(state: &mut SatsgotchiState, params: &GameParams, current_block: u64) -> u64 {
+    if state.status == Status::Dead {
+        return 0;
+    }
+    
+    let today = current_block / QUEST_DAY_BLOCKS;
+    let mut completed = 0u64;
+    
+    if state.quests.day != today {
+        // Yesterday's all-day quests count only if the pet was tracked
+        // through to the end of it
+        if state.quests.day + 1 == today {
+            for (slot, quest) in daily_quests(state.quests.day).iter().enumerate() {
+                if quest.is_all_day() && state.quests.completed & (1 << slot) == 0 && quest.is_met(&state.quests) {
+                    completed += 1;
+                }
+            }
+        }
+        state.quests = QuestProgress::new(today, state.hunger);
+    }
+    
+    state.quests.max_hunger = state.quests.max_hunger.max(state.hunger);
+    
+    for (slot, quest) in daily_quests(today).iter().enumerate() {
+        if !quest.is_all_day() && state.quests.completed & (1 << slot) == 0 && quest.is_met(&state.quests) {
+            state.quests.completed |= 1 << slot;
+            completed += 1;
+        }
+    }
+    
+    // Quests finished during a quick resale's cooldown aren't paid
+    let paid = if in_transfer_cooldown(state, params, current_block) {
+        0
+    } else {
+        completed * params.quest_reward
+    };
+    state.unclaimed_rewards = state.unclaimed_rewards.saturating_add(paid);
+    state.total_earned = state.total_earned.saturating_add(paid);
+    
+    paid
+}
+
+/// Friendship level reached after `visits` visits
+pub fn friendship_level(visits: u32) -> u8 {
+    FRIENDSHIP_LEVEL_VISITS.iter().filter(|needed| visits >= **needed).count() as u8
+}
+
+/// One pet visits another owner's pet: both gain happiness (more as their
+/// friendship grows) and the visit is logged. Returns the happiness each gained.
+pub fn apply_visit(
+    friendship: &mut Friendship,
+    visitor: &mut SatsgotchiState,
+    visitor_key: Pubkey,
+    host: &mut SatsgotchiState,
+    params: &GameParams,
+    current_block: u64,
+) -> Result<u8, ProgramError> {
+    if visitor.status == Status::Dead || host.status == Status::Dead {
+        return Err(ProgramError::Custom(1));
+    }
+    
+    // Visiting your own pets doesn't count
+    if visitor.owner == host.owner {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    if friendship.visits > 0
+        && current_block < friendship.last_visit_block.saturating_add(params.visit_cooldown_blocks)
+    {
+        return Err(ProgramError::Custom(8)); // Action on cooldown
+    }
+    
+    friendship.visits = friendship.visits.saturating_add(1);
+    friendship.level = friendship_level(friendship.visits);
+    friendship.last_visit_block = current_block;
+    friendship.log.push(VisitRecord { visitor: visitor_key, block: current_block });
+    if friendship.log.len() > MAX_VISIT_LOG {
+        friendship.log.remove(0);
+    }
+    
+    let happiness = params.visit_happiness
+        .saturating_add(friendship.level.saturating_mul(params.friendship_happiness_per_level));
+    
+    for pet in [visitor, host] {
+        let before = meters(pet);
+        pet.happiness = pet.happiness.saturating_add(happiness).min(100);
+        pet.best_friendship = pet.best_friendship.max(friendship.level);
+        record_event(pet, PetEventKind::Visit, current_block, before);
+    }
+    
+    Ok(happiness)
+}
+
+/// Check a pet name and return its registry key (lowercase, so names are
+/// unique regardless of case)
+pub fn normalize_name(name: &str) -> Result<String, ProgramError> {
+    let allowed = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == ' ';
+    
+    if name.len() < MIN_NAME_LEN
+        || name.len() > MAX_NAME_LEN
+        || !name.chars().all(allowed)
+        || name.starts_with(' ')
+        || name.ends_with(' ')
+        || name.contains("  ")
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    Ok(name.to_ascii_lowercase())
+}
+
+/// Whether a pet's name can be released: dead for the whole grace period
+pub fn is_name_releasable(state: &SatsgotchiState, params: &GameParams, current_block: u64) -> bool {
+    state.status == Status::Dead
+        && current_block >= state.died_block.saturating_add(params.name_grace_blocks)
+}
+
+/// Apply a care action by its CARE_* flag (used by CareAll)
+pub fn apply_care(state: &mut SatsgotchiState, action: u8, current_block: u64) -> Result<(), ProgramError> {
+    match action {
+        CARE_FEED => apply_feed(state, current_block),
+        CARE_PLAY => apply_play(state, current_block),
+        CARE_CLEAN => apply_clean(state, current_block),
+        CARE_MEDICINE => apply_medicine(state, current_block),
+        _ => Err(ProgramError::InvalidArgument),
+    }
+}
+
+/// The first block a pet could die if nobody cares for it again, assuming
+/// the harshest level it can still evolve into and that it turns frail
+pub fn earliest_death_block(state: &SatsgotchiState, params: &GameParams) -> u64 {
+    let params = species_params(params, &state.traits);
+    let levels = [&params.baby, &params.child, &params.teen, &params.adult, &params.senior];
+    let remaining = match state.level {
+        Level::Egg | Level::Baby => &levels[..],
+        Level::Child => &levels[1..],
+        Level::Teen => &levels[2..],
+        Level::Adult => &levels[3..],
+        Level::Senior => &levels[4..],
+        Level::Ascended => return u64::MAX, // Never dies
+    };
+    
+    // Starved: unfed for more days than the (frail) tolerance
+    let tolerance = remaining.iter().map(|level| level.death_neglect_days).min().unwrap_or(0).saturating_sub(1);
+    let starved = state.last_fed_block.saturating_add(tolerance.saturating_add(1).saturating_mul(144));
+    
+    // Worn out: health gone a point per decay period, the first one next block
+    let decay_blocks = remaining.iter().map(|level| level.health_decay_blocks).min().unwrap_or(1);
+    let worn_out = state.last_update_block
+        .saturating_add((state.health.saturating_sub(1) as u64).saturating_mul(decay_blocks))
+        .saturating_add(1);
+    
+    starved.min(worn_out)
+}
+
+/// Earnings multiplier points for keeping several household pets alive
+pub fn household_bonus(params: &GameParams, alive_pets: usize) -> u16 {
+    let extra_pets = alive_pets.saturating_sub(1).min(u16::MAX as usize) as u16;
+    
+    extra_pets.saturating_mul(params.household_bonus_per_pet)
+        .min(params.max_household_bonus)
+}
+
+/// Recompute a household's snapshot from its member pets and give every
+/// living member the household bonus. The bonus only holds until the first
+/// block any of them could die, since a death between CareAlls (e.g. on the
+/// crank) never reaches the other pets.
+pub fn refresh_household(
+    household: &mut Household,
+    params: &GameParams,
+    pets: &mut [SatsgotchiState],
+    current_block: u64,
+) {
+    let alive_pets = pets.iter().filter(|pet| pet.status == Status::Alive).count();
+    let bonus = household_bonus(params, alive_pets);
+    let bonus_until = pets.iter()
+        .filter(|pet| pet.status == Status::Alive)
+        .map(|pet| earliest_death_block(pet, params))
+        .min()
+        .unwrap_or(current_block);
+    
+    for pet in pets.iter_mut().filter(|pet| pet.status == Status::Alive) {
+        pet.household_bonus = bonus;
+        pet.household_bonus_until = bonus_until;
+    }
+    
+    household.alive_pets = alive_pets as u8;
+    household.total_earned = pets.iter().map(|pet| pet.total_earned).sum();
+    household.updated_block = current_block;
+}
+
+/// Current burn of every care action (dynamic with circulating supply)
+pub fn quote_burns(params: &GameParams, circulating_supply: u64) -> BurnQuote {
+    BurnQuote {
+        feed: calculate_burn_amount(params, circulating_supply, "feed"),
+        snack: calculate_burn_amount(params, circulating_supply, "snack"),
+        play: calculate_burn_amount(params, circulating_supply, "play"),
+        clean: calculate_burn_amount(params, circulating_supply, "clean"),
+        medicine: calculate_burn_amount(params, circulating_supply, "medicine"),
+        freeze_per_block: calculate_burn_amount(params, circulating_supply, "freeze"),
+        rename: calculate_burn_amount(params, circulating_supply, "rename"),
+    }
+}
+
+/// Feed windows that have fully passed since the last meal: the first ends
+/// `feed_threshold` blocks after it, the next one `feed_threshold` later
+fn missed_feed_windows(state: &SatsgotchiState, params: &GameParams, current_block: u64) -> u8 {
+    let feed_threshold = get_feed_threshold(params, &state.level).max(1);
+    let blocks_since_fed = current_block.saturating_sub(state.last_fed_block);
+    
+    (blocks_since_fed.saturating_sub(1) / feed_threshold).min(u8::MAX as u64) as u8
+}
+
+/// Project the pet forward with no care, cranked every hour, using the same
+/// tick as UpdateState. The care mistake block is exact (the block after the
+/// feed window closes), not the hour it is first seen. The pet itself is left
+/// untouched.
+pub fn forecast(state: &SatsgotchiState, params: &GameParams, current_block: u64) -> Forecast {
+    let mut forecast = Forecast::default();
+    if state.status == Status::Dead {
+        return forecast;
+    }
+    
+    let mut pet = state.clone();
+    let mut block = current_block;
+    let mut missed_before = pet.missed_feed_windows;
+    let outcome = apply_time_elapsed(&mut pet, params, 0, block);
+    
+    // Evolution only waits out the rest of any freeze
+    if pet.evolution_eligible_block != u64::MAX && !outcome.died {
+        let remaining_frozen = pet.frozen_until_block.saturating_sub(block);
+        forecast.next_evolution_block = Some(pet.evolution_eligible_block.max(block) + remaining_frozen);
+    }
+    
+    let mut outcome = outcome;
+    loop {
+        // The step only finds the mistake; it landed when the window ran out
+        if outcome.care_mistake && forecast.next_care_mistake_block.is_none() {
+            let feed_threshold = get_feed_threshold(&species_params(params, &pet.traits), &pet.level).max(1);
+            forecast.next_care_mistake_block = Some(pet.last_fed_block + (missed_before as u64 + 1) * feed_threshold + 1);
+        }
+        if outcome.died {
+            forecast.death_block = Some(block);
+            break;
+        }
+        if block >= current_block + FORECAST_HORIZON_BLOCKS {
+            break;
+        }
+        
+        block += BLOCKS_PER_HOUR;
+        missed_before = pet.missed_feed_windows;
+        outcome = apply_time_elapsed(&mut pet, params, 0, block);
+    }
+    
+    forecast
+}
+
+/// How far the pet's weight is outside the ideal band (0 inside it)
+pub fn weight_distance(state: &SatsgotchiState) -> u8 {
+    IDEAL_WEIGHT_MIN.saturating_sub(state.weight)
+        .max(state.weight.saturating_sub(IDEAL_WEIGHT_MAX))
+}
+
+/// Percent chance per day of falling sick
+pub fn sickness_chance_pct(state: &SatsgotchiState) -> u64 {
+    let weight_risk = 2 * weight_distance(state) as u64;
+    let fitness_risk = 50u64.saturating_sub(state.fitness as u64) / 5;
+    
+    (SICKNESS_BASE_PCT + weight_risk + fitness_risk).min(MAX_SICKNESS_PCT)
+}
+
+/// Per-pet seed for its daily rolls: the birth block mixed with the
+/// inscription id, so pets born in the same block roll independently.
+/// TODO: Mix in the birth block's hash once the runtime exposes it
+fn pet_seed(state: &SatsgotchiState) -> u64 {
+    state.inscription_id.bytes().fold(state.birth_block, |acc, byte| {
+        let mut seed = acc ^ byte as u64;
+        splitmix64(&mut seed)
+    })
+}
+
+/// Deterministic sickness roll for one day of the pet's life
+fn rolls_sick(state: &SatsgotchiState, day: u64) -> bool {
+    let mut seed = pet_seed(state) ^ day.rotate_left(32);
+    splitmix64(&mut seed) % 100 < sickness_chance_pct(state)
+}
+
+/// Sick, badly off-weight or out of shape
+pub fn is_frail(state: &SatsgotchiState) -> bool {
+    state.sick
+        || weight_distance(state) > FRAIL_WEIGHT_DISTANCE
+        || state.fitness < FRAIL_FITNESS
+}
+
+/// Overall condition, 0-100: the average of health, happiness, fullness,
+/// fitness and how close weight is to ideal, minus 20 while sick. Input for
+/// evolution branching and leaderboards.
+pub fn vitality_score(state: &SatsgotchiState) -> u8 {
+    if state.status == Status::Dead {
+        return 0;
+    }
+    
+    let weight_score = 100u16.saturating_sub(10 * weight_distance(state) as u16);
+    let total = state.health as u16
+        + state.happiness as u16
+        + (100 - state.hunger.min(100)) as u16
+        + state.fitness as u16
+        + weight_score;
+    let sick_penalty = if state.sick { 20 } else { 0 };
+    
+    ((total / 5) as u8).saturating_sub(sick_penalty)
+}
+
+impl EventHistory {
+    /// Add an event, overwriting the oldest once `HISTORY_LEN` are stored
+    pub fn push(&mut self, event: PetEvent) {
+        if self.events.len() < HISTORY_LEN {
+            self.events.push(event);
+        } else {
+            let slot = self.next as usize % HISTORY_LEN;
+            self.events[slot] = event;
+            self.next = ((slot + 1) % HISTORY_LEN) as u8;
+        }
+    }
+    
+    /// Stored events, oldest first
+    pub fn timeline(&self) -> Vec<PetEvent> {
+        let split = self.next as usize % self.events.len().max(1);
+        let (newer, older) = self.events.split_at(split);
+        older.iter().chain(newer).cloned().collect()
+    }
+}
+
+/// (health, happiness, hunger) for history deltas
+fn meters(state: &SatsgotchiState) -> (u8, u8, u8) {
+    (state.health, state.happiness, state.hunger)
+}
+
+fn record_event(state: &mut SatsgotchiState, kind: PetEventKind, block: u64, before: (u8, u8, u8)) {
+    let delta = |after: u8, before: u8| (after as i16 - before as i16) as i8;
+    let event = PetEvent {
+        kind,
+        block,
+        health_delta: delta(state.health, before.0),
+        happiness_delta: delta(state.happiness, before.1),
+        hunger_delta: delta(state.hunger, before.2),
+        care_mistakes: state.care_mistakes,
+    };
+    state.history.push(event);
+}
+
+/// Next value of the splitmix64 sequence for `seed`
+fn splitmix64(seed: &mut u64) -> u64 {
+    *seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *seed;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Split a canonical `<txid>i<index>` inscription id (64 lowercase hex
+/// digits, `i`, decimal index without leading zeros) into its parts
+pub fn parse_inscription_id(inscription_id: &str) -> Result<([u8; 32], u64), ProgramError> {
+    let (txid_hex, index) = inscription_id.split_once('i')
+        .ok_or(ProgramError::InvalidArgument)?;
+    
+    let hex_digit = |c: u8| match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        _ => None,
+    };
+    
+    if txid_hex.len() != 64 {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let mut txid = [0u8; 32];
+    for (byte, pair) in txid.iter_mut().zip(txid_hex.as_bytes().chunks(2)) {
+        let (high, low) = hex_digit(pair[0]).zip(hex_digit(pair[1]))
+            .ok_or(ProgramError::InvalidArgument)?;
+        *byte = high << 4 | low;
+    }
+    
+    // One spelling per inscription, so nobody can initialize it twice
+    if index.is_empty()
+        || !index.bytes().all(|c| c.is_ascii_digit())
+        || (index.len() > 1 && index.starts_with('0'))
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let index = index.parse().map_err(|_| ProgramError::InvalidArgument)?;
+    
+    Ok((txid, index))
+}
+
+/// The traits an inscription is born with. The txid is already a hash, so
+/// it seeds the rolls directly; nothing the caller chooses affects them.
+pub fn derive_traits(inscription_id: &str) -> Result<Traits, ProgramError> {
+    let (txid, index) = parse_inscription_id(inscription_id)?;
+    
+    let mut seed = txid.chunks(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+        .fold(index, |acc, word| acc ^ word);
+    
+    let roll = splitmix64(&mut seed) % 10_000;
+    let mut cumulative = 0;
+    let rarity = RARITY_ODDS_BPS.iter()
+        .position(|odds| {
+            cumulative += odds;
+            roll < cumulative
+        })
+        .unwrap_or(0) as u8;
+    
+    Ok(Traits {
+        rarity,
+        color_shift: (splitmix64(&mut seed) % (MAX_COLOR_SHIFT as u64 + 1)) as u16,
+        pet_type: (splitmix64(&mut seed) % SPECIES.len() as u64) as u8,
+        accessories: Vec::new(),
+    })
+}
+
+impl Traits {
+    /// Reject traits outside the ranges the game understands
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        if self.rarity > MAX_RARITY
+            || self.color_shift > MAX_COLOR_SHIFT
+            || self.pet_type as usize >= SPECIES.len()
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        
+        Ok(())
+    }
+    
+    pub fn species(&self) -> &'static Species {
+        SPECIES.get(self.pet_type as usize).unwrap_or(&SPECIES[0])
+    }
+}
+
+/// The GameConfig values as they apply to one pet: scaled by its species,
+/// with rarity earning more and decaying slower (within the species bounds)
+pub fn species_params(params: &GameParams, traits: &Traits) -> GameParams {
+    let species = traits.species();
+    let rarity = traits.rarity.min(MAX_RARITY) as u16;
+    let bounded = |pct: u16| pct.clamp(MIN_SPECIES_PCT, MAX_SPECIES_PCT) as u64;
+    
+    let decay_pct = bounded(species.decay_pct.saturating_sub(rarity * RARITY_DECAY_BONUS_PCT));
+    let hunger_pct = bounded(species.hunger_pct);
+    let earning_pct = bounded(species.earning_pct + rarity * RARITY_EARNING_BONUS_PCT);
+    let evolution_pct = bounded(species.evolution_pct);
+    
+    // Faster decay means fewer blocks per point lost
+    let period = |blocks: u64, pct: u64| (blocks * 100 / pct).max(1);
+    
+    let mut pet = params.clone();
+    pet.hunger_period_blocks = period(params.hunger_period_blocks, hunger_pct);
+    pet.happiness_period_blocks = period(params.happiness_period_blocks, decay_pct);
+    pet.ascended_reward_rate_ppb = params.ascended_reward_rate_ppb * earning_pct / 100;
+    
+    for level in [&mut pet.baby, &mut pet.child, &mut pet.teen, &mut pet.adult, &mut pet.senior] {
+        level.health_decay_blocks = period(level.health_decay_blocks, decay_pct);
+        level.evolution_blocks = (level.evolution_blocks * evolution_pct / 100).max(1);
+        level.reward_rate_ppb = level.reward_rate_ppb * earning_pct / 100;
+    }
+    
+    pet
+}
+
+/// Weather modifiers for the blocks after `from_block` up to `to_block`:
+/// each reading covers the WEATHER_MAX_AGE_BLOCKS after it was observed (or
+/// until the next reading), storms drain happiness faster and quiet blocks
+/// raise it. Counted in periods of block height, so splitting time across
+/// updates changes nothing. Returns the happiness change.
+pub fn apply_weather(
+    state: &mut SatsgotchiState,
+    params: &GameParams,
+    weather: &WeatherState,
+    from_block: u64,
+    to_block: u64,
+) -> i16 {
+    if state.status == Status::Dead {
+        return 0;
+    }
+    
+    let before = state.happiness;
+    
+    for (i, reading) in weather.readings.iter().enumerate() {
+        let mut covered_until = reading.observed_block.saturating_add(WEATHER_MAX_AGE_BLOCKS);
+        if let Some(next) = weather.readings.get(i + 1) {
+            covered_until = covered_until.min(next.observed_block);
+        }
+        
+        let start = from_block.max(reading.observed_block);
+        let end = to_block.min(covered_until);
+        if end <= start {
+            continue;
+        }
+        
+        let periods = |period: u64| {
+            let period = period.max(1);
+            (end / period - start / period).min(100) as u8
+        };
+        
+        state.happiness = match reading.weather {
+            Weather::Storm => state.happiness.saturating_sub(periods(params.storm_drain_period_blocks)),
+            Weather::Quiet => state.happiness.saturating_add(periods(params.quiet_boost_period_blocks)).min(100),
+            Weather::Normal => state.happiness,
+        };
+    }
+    
+    state.happiness as i16 - before as i16
+}
+
+/// The Bitcoin event calendar: every event whose effect window covers `block`
+pub fn active_bitcoin_events(params: &GameParams, block: u64) -> Vec<BitcoinEvent> {
+    let mut events = Vec::new();
+    
+    let epoch = block / HALVING_INTERVAL;
+    if epoch > 0 && block % HALVING_INTERVAL < params.halving_bonus_blocks {
+        events.push(BitcoinEvent::Halving { epoch });
+    }
+    
+    let period = block / DIFFICULTY_ADJUSTMENT_INTERVAL;
+    if period > 0 && block % DIFFICULTY_ADJUSTMENT_INTERVAL < params.difficulty_window_blocks {
+        events.push(BitcoinEvent::DifficultyAdjustment { period });
+    }
+    
+    events
+}
+
+/// Blocks in [from_block, to_block) that fall inside a halving bonus window
+fn halving_bonus_blocks(params: &GameParams, from_block: u64, to_block: u64) -> u64 {
+    let first_epoch = (from_block / HALVING_INTERVAL).max(1);
+    let last_epoch = to_block / HALVING_INTERVAL;
+    
+    (first_epoch..=last_epoch)
+        .map(|epoch| {
+            let start = epoch * HALVING_INTERVAL;
+            let end = start + params.halving_bonus_blocks;
+            let lo = start.max(from_block);
+            let hi = end.min(to_block);
+            hi.saturating_sub(lo)
+        })
+        .sum()
+}
+
+/// Give the pet this halving's limited-edition accessory if it is alive
+/// during the halving window and doesn't have it yet
+fn drop_event_accessory(state: &mut SatsgotchiState, params: &GameParams, current_block: u64) -> Option<u8> {
+    let epoch = active_bitcoin_events(params, current_block).iter().find_map(|event| match event {
+        BitcoinEvent::Halving { epoch } => Some(*epoch),
+        _ => None,
+    })?;
+    let accessory = HALVING_ACCESSORY_BASE.checked_add(u8::try_from(epoch).ok()?)?;
+    
+    if state.traits.accessories.contains(&accessory) {
+        return None;
+    }
+    state.traits.accessories.push(accessory);
+    Some(accessory)
+}
+
+/// Close the current streak into `streak_history` (credited to the owner)
+fn end_streak(state: &mut SatsgotchiState, current_block: u64) {
+    if state.current_streak_days == 0 {
+        return;
+    }
+    
+    if state.streak_history.len() >= MAX_STREAK_HISTORY {
+        state.streak_history.remove(0);
+    }
+    state.streak_history.push(StreakRecord {
+        owner: state.owner,
+        days: state.current_streak_days,
+        ended_block: current_block,
+    });
+    state.current_streak_days = 0;
+}
+
+/// Longest streak `owner` has kept on this pet, including the running one
+pub fn best_streak_for(state: &SatsgotchiState, owner: &Pubkey) -> u16 {
+    let running = if state.owner == *owner { state.current_streak_days } else { 0 };
+    
+    state.streak_history.iter()
+        .filter(|record| record.owner == *owner)
+        .map(|record| record.days)
+        .fold(running, u16::max)
+}
+
+/// Earnings multiplier points the current streak adds on top of `care_multiplier`
+pub fn streak_bonus(state: &SatsgotchiState, params: &GameParams) -> u16 {
+    state.current_streak_days
+        .saturating_mul(params.streak_bonus_per_day)
+        .min(params.max_streak_bonus)
+}
+
+/// Unlock every achievement whose condition the pet now meets, applying
+/// multiplier bonuses once. Returns the newly unlocked badges.
+pub fn unlock_achievements(state: &mut SatsgotchiState) -> u32 {
+    let mut earned = 0;
+    if state.level >= Level::Child {
+        earned |= BADGE_FIRST_EVOLUTION;
+    }
+    if state.perfect_care_days >= 30 {
+        earned |= BADGE_PERFECT_MONTH;
+    }
+    if state.level == Level::Senior && state.care_mistakes == 0 {
+        earned |= BADGE_FLAWLESS_SENIOR;
+    }
+    if state.age_blocks >= 100 * 144 {
+        earned |= BADGE_CENTENARIAN;
+    }
+    if state.level == Level::Ascended {
+        earned |= BADGE_ASCENDED;
+    }
+    if state.best_friendship >= MAX_FRIENDSHIP_LEVEL {
+        earned |= BADGE_BEST_FRIENDS;
+    }
+    
+    let unlocked = earned & !state.badges;
+    for achievement in ACHIEVEMENTS.iter().filter(|a| unlocked & a.badge != 0) {
+        state.care_multiplier = state.care_multiplier.saturating_add(achievement.multiplier_bonus);
+    }
+    state.badges |= unlocked;
+    
+    unlocked
+}
+
+/// Apply decay, neglect, death and reward accrual for the blocks since
+/// `last_update_block`. Dead pets and same-block calls are left untouched.
+pub fn apply_time_elapsed(
+    state: &mut SatsgotchiState,
+    params: &GameParams,
+    circulating_supply: u64,
+    current_block: u64,
+) -> TickOutcome {
+    let mut outcome = TickOutcome::default();
+    
+    if state.status == Status::Dead {
+        return outcome; // Dead pets don't update
+    }
+    
+    let params = &species_params(params, &state.traits);
+    let before = meters(state);
+    
+    // Calculate time elapsed in blocks
+    let total_blocks = current_block.saturating_sub(state.last_update_block);
+    
+    if total_blocks == 0 {
+        return outcome;
+    }
+    
+    // Frozen time doesn't count: push every timer forward past it
+    let frozen_blocks = frozen_blocks_between(state, current_block);
+    if frozen_blocks > 0 {
+        state.last_fed_block += frozen_blocks;
+        state.last_played_block += frozen_blocks;
+        state.last_cleaned_block += frozen_blocks;
+        state.evolution_eligible_block = state.evolution_eligible_block.saturating_add(frozen_blocks);
+        outcome.frozen_blocks = frozen_blocks;
+    }
+    
+    let blocks_elapsed = total_blocks - frozen_blocks;
+    outcome.blocks_elapsed = blocks_elapsed;
+    
+    if blocks_elapsed == 0 {
+        state.last_update_block = current_block;
+        return outcome;
+    }
+    
+    // Decay is counted in whole periods of the pet's age, so splitting time
+    // across many updates decays exactly as much as one long update
+    let age_before = state.age_blocks;
+    state.age_blocks += blocks_elapsed;
+    let periods = |period: u64| {
+        let period = period.max(1);
+        (state.age_blocks / period - age_before / period).min(u8::MAX as u64) as u8
+    };
+    let days_passed = periods(144);
+    
+    // Update hunger (increases over time)
+    let hunger_increase = periods(params.hunger_period_blocks).min(100);
+    state.hunger = (state.hunger + hunger_increase).min(100);
+    
+    // Decay health based on level
+    let health_decay = params.level(&state.level)
+        .map_or(0, |level| periods(level.health_decay_blocks));
+    state.health = state.health.saturating_sub(health_decay);
+    
+    // Decay happiness
+    state.happiness = state.happiness.saturating_sub(periods(params.happiness_period_blocks));
+    
+    // Fitness fades a point a day
+    state.fitness = state.fitness.saturating_sub(days_passed);
+    
+    // Roll for sickness once per day of age; poor condition raises the odds
+    let first_day = age_before / 144 + 1;
+    for day in first_day..first_day + days_passed as u64 {
+        if !state.sick && rolls_sick(state, day) {
+            state.sick = true;
+            outcome.fell_sick = true;
+        }
+    }
+    
+    // Check for care mistakes (neglect): one per feed window missed since
+    // the last meal, so splitting time across updates counts the same
+    let missed_windows = missed_feed_windows(state, params, current_block);
+    
+    if missed_windows > state.missed_feed_windows {
+        let new_mistakes = missed_windows - state.missed_feed_windows;
+        state.care_mistakes = state.care_mistakes.saturating_add(new_mistakes);
+        state.missed_feed_windows = missed_windows;
+        outcome.care_mistake = true;
+    }
+    
+    // Check for death
+    if state.health == 0 || should_die(state, params, current_block) {
+        state.status = Status::Dead;
+        state.died_block = current_block;
+        outcome.died = true;
+    }
+    
+    // Only notable ticks go into the history; the first event carries the deltas
+    let notable = [
+        (outcome.care_mistake, PetEventKind::CareMistake),
+        (outcome.fell_sick, PetEventKind::FellSick),
+        (outcome.died, PetEventKind::Died),
+    ];
+    let mut before = before;
+    for (_, kind) in notable.iter().filter(|(happened, _)| *happened) {
+        record_event(state, *kind, current_block, before);
+        before = meters(state);
+    }
+    
+    // Days without neglect that end with the pet in good shape count as perfect
+    let earns_care_credit = !in_transfer_cooldown(state, params, current_block);
+    if earns_care_credit && !outcome.care_mistake && !outcome.died && state.health >= 50 && state.happiness >= 50 {
+        state.perfect_care_days = state.perfect_care_days.saturating_add(days_passed as u16);
+    }
+    
+    // A missed feeding window (or death) ends the care streak
+    if outcome.care_mistake || outcome.died {
+        end_streak(state, current_block);
+    } else if earns_care_credit {
+        state.current_streak_days = state.current_streak_days.saturating_add(days_passed as u16);
+        state.longest_streak_days = state.longest_streak_days.max(state.current_streak_days);
+    }
+    
+    // Accumulate rewards; blocks inside a halving window earn double, and
+    // the household bonus stops at the block the last CareAll vouched for
+    let earned_before = state.total_earned;
+    let from_block = current_block - blocks_elapsed;
+    let household_end = state.household_bonus_until.clamp(from_block, current_block);
+    outcome.bonus_blocks = halving_bonus_blocks(params, from_block, current_block);
+    accumulate_rewards(state, params, circulating_supply,
+        household_end - from_block + halving_bonus_blocks(params, from_block, household_end));
+    if household_end < current_block {
+        state.household_bonus = 0;
+        accumulate_rewards(state, params, circulating_supply,
+            current_block - household_end + halving_bonus_blocks(params, household_end, current_block));
+    }
+    outcome.rewards_accrued = state.total_earned - earned_before;
+    
+    if !outcome.died {
+        outcome.badges_unlocked = unlock_achievements(state);
+        outcome.accessory_dropped = drop_event_accessory(state, params, current_block);
+    }
+    
+    outcome.quest_rewards = settle_quests(state, params, current_block);
+    
+    state.last_update_block = current_block;
+    
+    outcome
+}
+
+/// Hand the pet to `new_owner`. Rewards accrued up to the sale stay with the
+/// seller as a claimable `SettledRewards` entry; pet sitters are dropped.
+/// Returns the amount settled to the seller.
+pub fn apply_transfer(
+    state: &mut SatsgotchiState,
+    params: &GameParams,
+    circulating_supply: u64,
+    weather: Option<&WeatherState>,
+    new_owner: Pubkey,
+    current_block: u64,
+) -> u64 {
+    // Accrue up to the sale (dead pets still hand over what they earned)
+    apply_tick(state, params, circulating_supply, weather, current_block);
+    
+    let settled = std::mem::take(&mut state.unclaimed_rewards);
+    if settled > 0 {
+        let seller = state.owner;
+        match state.settled_rewards.iter_mut().find(|r| r.owner == seller) {
+            Some(entry) => entry.amount += settled,
+            None => state.settled_rewards.push(SettledRewards { owner: seller, amount: settled }),
+        }
+    }
+    
+    // The seller keeps credit for their streak; the buyer starts a new one
+    end_streak(state, current_block);
+    
+    let before = meters(state);
+    record_event(state, PetEventKind::Transfer, current_block, before);
+    
+    state.owner = new_owner;
+    state.caretakers.clear();
+    state.household_bonus = 0; // The buyer's household picks it up on CareAll
+    state.last_transfer_block = current_block;
+    
+    settled
+}
+
+/// Whether the pet changed hands less than `transfer_cooldown_blocks` ago.
+/// Until then the new owner earns no care credit (perfect care days,
+/// streak days, quest rewards), so flipping pets doesn't pay.
+pub fn in_transfer_cooldown(state: &SatsgotchiState, params: &GameParams, current_block: u64) -> bool {
+    state.last_transfer_block != 0
+        && current_block < state.last_transfer_block.saturating_add(params.transfer_cooldown_blocks)
+}
+
+/// Remove and return what's owed to a previous owner
+pub fn take_settled_rewards(state: &mut SatsgotchiState, owner: &Pubkey) -> Result<u64, ProgramError> {
+    let index = state.settled_rewards.iter()
+        .position(|r| r.owner == *owner)
+        .ok_or(ProgramError::Custom(4))?; // No rewards to claim
+    
+    Ok(state.settled_rewards.remove(index).amount)
+}
+
+/// `apply_time_elapsed` plus every weather reading (if any) over the
+/// unfrozen blocks it covered. Every instruction that advances a pet ticks through here.
+pub fn apply_tick(
+    state: &mut SatsgotchiState,
+    params: &GameParams,
+    circulating_supply: u64,
+    weather: Option<&WeatherState>,
+    current_block: u64,
+) -> TickOutcome {
+    // Frozen time always starts at the last update and sees no weather
+    let from_block = state.last_update_block.max(state.frozen_until_block);
+    
+    let mut outcome = apply_time_elapsed(state, params, circulating_supply, current_block);
+    
+    if let Some(weather) = weather {
+        outcome.weather_happiness = apply_weather(state, params, weather, from_block, current_block);
+    }
+    
+    outcome
+}
+
+/// Bring a pet up to `current_block` before an action runs. Uses the same
+/// tick as UpdateState and fails if the pet is (or by now has) died.
+pub fn settle_elapsed_time(
+    state: &mut SatsgotchiState,
+    params: &GameParams,
+    circulating_supply: u64,
+    weather: Option<&WeatherState>,
+    current_block: u64,
+) -> Result<TickOutcome, ProgramError> {
+    let outcome = apply_tick(state, params, circulating_supply, weather, current_block);
+    
+    if state.status == Status::Dead {
+        return Err(ProgramError::Custom(1)); // Pet is dead
+    }
+    
+    Ok(outcome)
+}
+
+/// Put the pet on vacation for `blocks`, extending any freeze in progress.
+/// Pending decay up to `current_block` is settled first so the freeze starts clean.
+pub fn apply_freeze(
+    state: &mut SatsgotchiState,
+    params: &GameParams,
+    circulating_supply: u64,
+    weather: Option<&WeatherState>,
+    blocks: u64,
+    current_block: u64,
+) -> Result<(), ProgramError> {
+    if state.status == Status::Dead {
+        return Err(ProgramError::Custom(1));
+    }
+    
+    if blocks == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    if state.total_frozen_blocks + blocks > MAX_LIFETIME_FROZEN_BLOCKS {
+        return Err(ProgramError::Custom(7)); // Lifetime freeze allowance used up
+    }
+    
+    settle_elapsed_time(state, params, circulating_supply, weather, current_block)?;
+    
+    let start = state.frozen_until_block.max(current_block);
+    state.frozen_until_block = start + blocks;
+    state.total_frozen_blocks += blocks;
+    
+    let before = meters(state);
+    record_event(state, PetEventKind::Freeze, current_block, before);
+    
+    Ok(())
+}
+
+/// Blocks between `last_update_block` and `current_block` covered by a freeze
+fn frozen_blocks_between(state: &SatsgotchiState, current_block: u64) -> u64 {
+    // Freezes always start at a settled `last_update_block`, so the frozen
+    // window is [last_update_block, frozen_until_block)
+    state.frozen_until_block
+        .min(current_block)
+        .saturating_sub(state.last_update_block)
+}
+
+impl KeeperState {
+    pub fn new(keeper: Pubkey) -> Self {
+        Self {
+            keeper,
+            total_updates: 0,
+            rewarded_updates: 0,
+            total_rewards: 0,
+            unclaimed_rewards: 0,
+            last_reward_block: 0,
+            rewards_this_block: 0,
+        }
+    }
+}
+
+/// Record a pet update for a keeper and credit `KEEPER_REWARD` when the pet
+/// advanced at least `KEEPER_MIN_BLOCKS`, hasn't paid a keeper in the last
+/// `KEEPER_MIN_BLOCKS` blocks, and the keeper is under its per-block limit.
+/// `current_block` is the runtime's block height. Returns whether a reward
+/// was credited.
+pub fn credit_keeper(
+    keeper: &mut KeeperState,
+    pet: &mut SatsgotchiState,
+    outcome: &TickOutcome,
+    current_block: u64,
+) -> bool {
+    keeper.total_updates += 1;
+    
+    if outcome.blocks_elapsed + outcome.frozen_blocks < KEEPER_MIN_BLOCKS {
+        return false;
+    }
+    
+    // Per pet, so extra keeper accounts can't step one pet forward for more
+    if pet.last_keeper_reward_block != 0
+        && current_block < pet.last_keeper_reward_block.saturating_add(KEEPER_MIN_BLOCKS)
+    {
+        return false;
+    }
+    
+    if keeper.last_reward_block != current_block {
+        keeper.last_reward_block = current_block;
+        keeper.rewards_this_block = 0;
+    }
+    
+    if keeper.rewards_this_block >= MAX_KEEPER_REWARDS_PER_BLOCK {
+        return false;
+    }
+    
+    keeper.rewards_this_block += 1;
+    keeper.rewarded_updates += 1;
+    pet.last_keeper_reward_block = current_block;
+    keeper.total_rewards += KEEPER_REWARD;
+    keeper.unclaimed_rewards += KEEPER_REWARD;
+    
+    true
+}
+
+/// Alive, not yet Ascended, and past its evolution block
+pub fn is_evolution_ready(state: &SatsgotchiState, current_block: u64) -> bool {
+    state.status == Status::Alive
+        && state.level != Level::Ascended
+        && current_block >= state.evolution_eligible_block
+}
+
+/// Advance to the next level, returning the milestone reward in $GOTCHI
+pub fn apply_evolve(
+    state: &mut SatsgotchiState,
+    params: &GameParams,
+    current_block: u64,
+) -> Result<u64, ProgramError> {
+    if state.status == Status::Dead {
+        return Err(ProgramError::Custom(1));
+    }
+    
+    let before = meters(state);
+    
+    let params = &species_params(params, &state.traits);
+    
+    // Check if eligible for evolution (earlier right after a difficulty adjustment)
+    let early_blocks = if active_bitcoin_events(params, current_block).iter()
+        .any(|event| matches!(event, BitcoinEvent::DifficultyAdjustment { .. }))
+    {
+        params.difficulty_evolution_early_blocks
+    } else {
+        0
+    };
+    if current_block.saturating_add(early_blocks) < state.evolution_eligible_block {
+        return Err(ProgramError::Custom(2)); // Not ready to evolve
+    }
+    
+    // Evolve to next level
+    let new_level = match state.level {
+        Level::Baby => Level::Child,
+        Level::Child => Level::Teen,
+        Level::Teen => Level::Adult,
+        Level::Adult => Level::Senior,
+        Level::Senior => Level::Ascended, // Immortal
+        _ => return Err(ProgramError::Custom(3)), // Already at max level
+    };
+    let reward_amount = params.level(&state.level)
+        .map_or(0, |level| level.evolution_reward);
+    
+    state.evolution_eligible_block = match params.level(&new_level) {
+        Some(next) => current_block + next.evolution_blocks,
+        None => u64::MAX, // Ascended = no more evolution
+    };
+    state.level = new_level;
+    
+    state.total_earned += reward_amount;
+    unlock_achievements(state);
+    
+    record_event(state, PetEventKind::Evolve, current_block, before);
+    
+    Ok(reward_amount)
+}
+
+// ============================================================================
+// HELPER FUNCTIONS
+// ============================================================================
+
+/// Split off an optional trailing [keeper_account, keeper_signer] pair.
+/// Pet accounts never sign, so a signing last account marks the keeper pair.
+fn split_keeper_accounts<'a, 'b>(
+    accounts: &'a [AccountInfo<'b>],
+) -> (&'a [AccountInfo<'b>], Option<(&'a AccountInfo<'b>, &'a AccountInfo<'b>)>) {
+    match accounts {
+        [pets @ .., keeper_account, keeper_signer] if keeper_signer.is_signer => {
+            (pets, Some((keeper_account, keeper_signer)))
+        }
+        _ => (accounts, None),
+    }
+}
+
+/// Address of the GameConfig account (PDA of CONFIG_SEED)
+pub fn config_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_SEED], program_id).0
+}
+
+/// Load the GameConfig account, rejecting anything but the program's own
+fn load_config(config_account: &AccountInfo, program_id: &Pubkey) -> Result<GameConfig, ProgramError> {
+    if *config_account.key != config_address(program_id) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    
+    GameConfig::try_from_slice(&config_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+pub fn weather_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[WEATHER_SEED], program_id).0
+}
+
+/// Read the weather account that instructions advancing pet time take
+/// right after TokenState. Required so the weather can't be dodged by
+/// leaving it out; before the oracle is set up it holds no readings.
+fn load_weather(
+    program_id: &Pubkey,
+    weather_account: &AccountInfo,
+) -> Result<Option<WeatherState>, ProgramError> {
+    if *weather_account.key != weather_address(program_id) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    
+    if weather_account.data.borrow().is_empty() {
+        return Ok(None);
+    }
+    
+    WeatherState::try_from_slice(&weather_account.data.borrow())
+        .map(Some)
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// `load_weather` for processors that walk their accounts in order
+fn next_weather_account(
+    program_id: &Pubkey,
+    account_iter: &mut std::slice::Iter<AccountInfo>,
+) -> Result<Option<WeatherState>, ProgramError> {
+    let weather_account = account_iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    load_weather(program_id, weather_account)
+}
+
+/// Circulating $GOTCHI supply from the token program's state account
+fn load_circulating_supply(
+    token_state_account: &AccountInfo,
+    config: &GameConfig,
+) -> Result<u64, ProgramError> {
+    if *token_state_account.key != config.token_state {
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    let token_state = TokenState::load(&token_state_account.data.borrow())?;
+    
+    Ok(token_state.circulating_supply)
+}
+
+/// Burn `amount` $GOTCHI from the payer's token account through the token
+/// program's `Burn` (the payer signed the outer transaction). The token
+/// program is whoever owns the TokenState already checked against GameConfig.
+/// The payer is the signer, so a caretaker pays from their own account.
+fn burn_gotchi<'a>(
+    token_program_account: &AccountInfo<'a>,
+    gotchi_token_account: &AccountInfo<'a>,
+    payer_account: &AccountInfo<'a>,
+    token_state_account: &AccountInfo<'a>,
+    amount: u64,
+) -> Result<(), ProgramError> {
+    if token_program_account.key != token_state_account.owner {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    
+    if amount == 0 {
+        return Ok(());
+    }
+    
+    if !payer_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    let payer_balance = AccountBalance::try_from_slice(&gotchi_token_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    check_burn_payer(&payer_balance, payer_account.key, amount)?;
+    
+    let instruction = Instruction {
+        program_id: *token_program_account.key,
+        accounts: vec![
+            AccountMeta { pubkey: *gotchi_token_account.key, is_signer: false, is_writable: true },
+            AccountMeta { pubkey: *payer_account.key, is_signer: true, is_writable: false },
+            AccountMeta { pubkey: *token_state_account.key, is_signer: false, is_writable: true },
+        ],
+        data: TokenInstruction::Burn { amount }.try_to_vec()
+            .map_err(|_| ProgramError::InvalidInstructionData)?,
+    };
+    
+    invoke(&instruction, &[
+        gotchi_token_account.clone(),
+        payer_account.clone(),
+        token_state_account.clone(),
+        token_program_account.clone(),
+    ])
+}
+
+/// Reject the instruction if the game is paused (GameConfig is the first account)
+fn check_not_paused(program_id: &Pubkey, accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+    let config_account = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let config = load_config(config_account, program_id)?;
+    
+    if config.paused {
+        msg!("Game is paused since block {}", config.paused_at_block);
+        return Err(ProgramError::Custom(10)); // Paused
+    }
+    
+    Ok(())
+}
+
+/// Load the GameConfig account and check the signer is its admin
+fn load_config_as_admin(
+    config_account: &AccountInfo,
+    admin_account: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<GameConfig, ProgramError> {
+    if !admin_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    let config = load_config(config_account, program_id)?;
+    
+    if config.admin != *admin_account.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    Ok(config)
+}
+
+/// Address of the Friendship account for two pets (in either order)
+pub fn friendship_address(program_id: &Pubkey, pet_a: &Pubkey, pet_b: &Pubkey) -> Pubkey {
+    let (low, high) = if pet_a < pet_b { (pet_a, pet_b) } else { (pet_b, pet_a) };
+    Pubkey::find_program_address(&[FRIENDSHIP_SEED, low.as_ref(), high.as_ref()], program_id).0
+}
+
+/// Address of the NameRecord for a normalized name (PDA of NAME_SEED + name)
+pub fn name_address(program_id: &Pubkey, key: &str) -> Pubkey {
+    Pubkey::find_program_address(&[NAME_SEED, key.as_bytes()], program_id).0
+}
+
+/// Address of an owner's Household account (PDA of HOUSEHOLD_SEED + owner)
+pub fn household_address(program_id: &Pubkey, owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[HOUSEHOLD_SEED, owner.as_ref()], program_id).0
+}
+
+/// An account with no data written yet (or released)
+fn is_zeroed(account: &AccountInfo) -> bool {
+    account.data.borrow().iter().all(|byte| *byte == 0)
+}
+
+/// Load the signer's household account (checking its PDA)
+fn load_household(
+    program_id: &Pubkey,
+    household_account: &AccountInfo,
+    owner_account: &AccountInfo,
+) -> Result<Household, ProgramError> {
+    if !owner_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    if *household_account.key != household_address(program_id, owner_account.key) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    
+    let household = Household::try_from_slice(&household_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    if household.owner != *owner_account.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    Ok(household)
+}
+
+/// Load a keeper account and check it belongs to the signer
+fn load_keeper(
+    keeper_account: &AccountInfo,
+    keeper_signer: &AccountInfo,
+) -> Result<KeeperState, ProgramError> {
+    if !keeper_signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    
+    let keeper = KeeperState::try_from_slice(&keeper_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    if keeper.keeper != *keeper_signer.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+    
+    Ok(keeper)
+}
+
+/// Count an update towards the keeper's stats and pay it if eligible. Limits
+/// use the runtime's block height; future-dated updates never get here, as
+/// `tick_pet_account` rejects them before ticking.
+fn record_keeper_update(
+    keeper: &mut KeeperState,
+    pet: &mut SatsgotchiState,
+    outcome: &TickOutcome,
+) {
+    if outcome.blocks_elapsed == 0 && outcome.frozen_blocks == 0 {
+        return; // Nothing was advanced
+    }
+    
+    if credit_keeper(keeper, pet, outcome, get_current_block()) {
+        msg!("Keeper reward: {} (unclaimed: {})", KEEPER_REWARD, keeper.unclaimed_rewards);
+    }
+}
+
+/// Reject a care action that is still on cooldown, logging when it's available
+fn check_cooldown(state: &SatsgotchiState, action: u8, current_block: u64) -> Result<(), ProgramError> {
+    let available_block = next_action_block(state, action);
+    
+    if current_block < available_block {
+        msg!("Action on cooldown, available again at block {}", available_block);
+        return Err(ProgramError::Custom(8)); // Action on cooldown
+    }
+    
+    Ok(())
+}
+
+fn log_tick(state: &SatsgotchiState, outcome: &TickOutcome) {
+    if outcome.care_mistake {
+        msg!("Care mistake! Total: {}", state.care_mistakes);
+    }
+    
+    if outcome.weather_happiness != 0 {
+        msg!("Weather: happiness {:+}", outcome.weather_happiness);
+    }
+    
+    if outcome.fell_sick {
+        msg!("Satsgotchi is sick! Vitality: {}", vitality_score(state));
+    }
+    
+    if outcome.died {
+        msg!("Satsgotchi died!");
+    }
+    
+    if outcome.bonus_blocks > 0 {
+        msg!("Halving bonus: double earnings for {} blocks", outcome.bonus_blocks);
+    }
+    
+    if let Some(accessory) = outcome.accessory_dropped {
+        msg!("Limited-edition halving accessory dropped: #{}", accessory);
+    }
+    
+    log_badges(outcome.badges_unlocked);
+    log_quest_rewards(outcome.quest_rewards);
+}
+
+fn log_quest_rewards(paid: u64) {
+    if paid > 0 {
+        msg!("Daily quest complete! Reward: {} $GOTCHI", paid);
+    }
+}
+
+fn log_badges(unlocked: u32) {
+    for achievement in ACHIEVEMENTS.iter().filter(|a| unlocked & a.badge != 0) {
+        msg!("Achievement unlocked: {} (+{} care multiplier)",
+            achievement.name, achievement.multiplier_bonus);
+    }
+}
+
+/// Merge a pet's badges and the owner's best streak on it into their
+/// OwnerAchievements account, if the caller passed one as the optional last account
+fn record_owner_achievements(
+    achievements_account: Option<&AccountInfo>,
+    state: &SatsgotchiState,
+) -> Result<(), ProgramError> {
+    record_household_achievements(achievements_account, std::slice::from_ref(state))
+}
+
+/// Merge several of the owner's pets into their OwnerAchievements account
+/// at once (CareAll), writing it only if anything is new
+fn record_household_achievements(
+    achievements_account: Option<&AccountInfo>,
+    pets: &[SatsgotchiState],
+) -> Result<(), ProgramError> {
+    let Some(achievements_account) = achievements_account else {
+        return Ok(());
+    };
+    
+    let mut record = OwnerAchievements::try_from_slice(&achievements_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let before = record.clone();
+    
+    for state in pets {
+        if record.owner != state.owner {
+            return Err(ProgramError::IllegalOwner);
+        }
+        
+        record.badges |= state.badges;
+        record.longest_streak_days = record.longest_streak_days.max(best_streak_for(state, &record.owner));
+    }
+    
+    if record == before {
+        return Ok(()); // Nothing new
+    }
+    
+    let serialized_record = record.try_to_vec()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    add_state_transition(achievements_account, serialized_record);
+    
+    Ok(())
+}
+
+/// Tick one pet account and persist it if anything changed
+/// (shared by UpdateState and BatchUpdateState). `current_block` can't be
+/// ahead of the runtime's block height, and it alone decides which
+/// parameters are in force, how much paused time to skip and how far to tick.
+fn tick_pet_account(
+    state_account: &AccountInfo,
+    config: &GameConfig,
+    circulating_supply: u64,
+    weather: Option<&WeatherState>,
+    keeper: Option<&mut KeeperState>,
+    current_block: u64,
+) -> Result<(SatsgotchiState, TickOutcome), ProgramError> {
+    check_update_block(current_block, get_current_block())?;
+    
+    let mut state = SatsgotchiState::try_from_slice(&state_account.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    
+    credit_paused_blocks(&mut state, config.total_paused_blocks(current_block));
+    
+    let params = config.active_params(current_block);
+    let outcome = apply_tick(&mut state, params, circulating_supply, weather, current_block);
+    
+    if let Some(keeper) = keeper {
+        record_keeper_update(keeper, &mut state, &outcome);
+    }
+    
+    // Dead pets and same-block updates don't change state
+    if outcome.blocks_elapsed > 0 || outcome.frozen_blocks > 0 {
+        let serialized_state = state.try_to_vec()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        
+        add_state_transition(state_account, serialized_state);
+    }
+    
+    Ok((state, outcome))
+}
+
+/// Reject an UpdateState block ahead of the runtime's: anyone may crank, so a
+/// future block would fast-forward (and kill) other people's pets
+pub fn check_update_block(current_block: u64, runtime_block: u64) -> Result<(), ProgramError> {
+    if current_block > runtime_block {
+        msg!("Block {} is ahead of the chain ({})", current_block, runtime_block);
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    Ok(())
+}
+
+fn get_current_block() -> u64 {
+    // In production, this comes from Arch runtime
+    // Returns current Bitcoin block height
+    800_000 // Placeholder for actual implementation
+}
+
+/// What an action burns; every processor and `quote_burns` price through
+/// here. Care actions scale with circulating supply (the token program's
+/// table); freeze and rename are flat config amounts.
+fn calculate_burn_amount(params: &GameParams, circulating_supply: u64, action: &str) -> u64 {
+    match action {
+        "feed" => calculate_dynamic_burn("feed_meal", circulating_supply),
+        "snack" => calculate_dynamic_burn("feed_snack", circulating_supply),
+        "play" => calculate_dynamic_burn("play_game", circulating_supply),
+        "clean" => calculate_dynamic_burn("clean", circulating_supply),
+        "medicine" => calculate_dynamic_burn("medicine", circulating_supply),
+        "freeze" => params.freeze_burn_per_block, // Per frozen block
+        "rename" => params.rename_burn,
+        _ => 0,
+    }
+}
+
+fn is_poop_generated(current_block: u64) -> bool {
+    // In production, use Arch's random number generator
+    // For now, simple pseudo-random based on block
+    current_block % 5 == 0 // 20% chance
+}
+
+fn get_feed_threshold(params: &GameParams, level: &Level) -> u64 {
+    // Time windows in Bitcoin blocks (144 blocks ≈ 1 day);
+    // Ascended pets keep the 24 hour window
+    params.level(level).map_or(144, |level| level.feed_threshold_blocks)
+}
+
+fn should_die(state: &SatsgotchiState, params: &GameParams, current_block: u64) -> bool {
+    // Death probability based on level and neglect
+    let days_neglected = (current_block.saturating_sub(state.last_fed_block)) / 144;
+    
+    // Frail pets survive one day less of neglect
+    let tolerance = |days: u64| if is_frail(state) { days.saturating_sub(1) } else { days };
+    
+    params.level(&state.level)
+        .is_some_and(|level| days_neglected > tolerance(level.death_neglect_days))
+}
+
+fn accumulate_rewards(
+    state: &mut SatsgotchiState,
+    params: &GameParams,
+    circulating_supply: u64,
+    blocks_elapsed: u64,
+) {
+    // Earning rate: parts per billion of circulating supply per hour
+    // (6 blocks), accrued per block and scaled by the care multiplier
+    // plus the streak and household bonuses
+    let rate_ppb = match state.level {
+        Level::Ascended => params.ascended_reward_rate_ppb,
+        _ => params.level(&state.level).map_or(0, |level| level.reward_rate_ppb),
+    };
+    
+    // Exact integer math; whatever doesn't make a whole unit is carried
+    // in `reward_remainder`, so rounding never depends on update frequency
+    let multiplier = state.care_multiplier
+        .saturating_add(streak_bonus(state, params))
+        .saturating_add(state.household_bonus);
+    let numerator = circulating_supply as u128
+        * rate_ppb as u128
+        * multiplier as u128
+        * blocks_elapsed as u128
+        + state.reward_remainder as u128;
+    let denominator = REWARD_RATE_SCALE as u128 * 100 * BLOCKS_PER_HOUR as u128;
+    
+    let rewards = (numerator / denominator).min(u64::MAX as u128) as u64;
+    state.reward_remainder = (numerator % denominator) as u64;
+    
+    state.unclaimed_rewards = state.unclaimed_rewards.saturating_add(rewards);
+    state.total_earned = state.total_earned.saturating_add(rewards);
+}
+
+// ============================================================================
+// TESTS (would be in separate file in production)
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 150M $GOTCHI (9 decimals) in circulation
+    const SUPPLY: u64 = 150_000_000_000_000_000;
+
+    fn test_pet(current_block: u64) -> SatsgotchiState {
+        let traits = Traits {
+            rarity: 0,
+            color_shift: 0,
+            pet_type: 0,
+            accessories: Vec::new(),
+        };
+        SatsgotchiState::new(
+            "test-inscription".to_string(),
+            Pubkey::default(),
+            traits,
+            &GameParams::default(),
+            current_block,
+        )
+    }
+
+    #[test]
+    fn test_initialize() {
+        let state = test_pet(800_000);
+        assert_eq!(state.level, Level::Baby);
+        assert_eq!(state.status, Status::Alive);
+        assert_eq!(state.evolution_eligible_block, 801_008);
+    }
+
+    #[test]
+    fn test_feed() {
+        let mut state = test_pet(800_000);
+        state.hunger = 80;
+        apply_feed(&mut state, 800_100).unwrap();
+        assert_eq!(state.hunger, 30);
+        assert_eq!(state.last_fed_block, 800_100);
+
+        state.status = Status::Dead;
+        assert_eq!(apply_feed(&mut state, 800_200), Err(ProgramError::Custom(1)));
+    }
+
+    #[test]
+    fn test_evolution() {
+        let mut state = test_pet(800_000);
+        assert_eq!(apply_evolve(&mut state, &GameParams::default(), 801_000), Err(ProgramError::Custom(2)));
+        assert_eq!(apply_evolve(&mut state, &GameParams::default(), 801_008), Ok(50));
+        assert_eq!(state.level, Level::Child);
+        assert_eq!(state.evolution_eligible_block, 801_008 + 4_032);
+    }
+
+    #[test]
+    fn test_neglected_baby_dies() {
+        let mut state = test_pet(800_000);
+        let outcome = apply_time_elapsed(&mut state, &GameParams::default(), SUPPLY, 800_000 + 6 * 144);
+        assert!(outcome.care_mistake);
+        assert!(outcome.died);
+        assert_eq!(state.status, Status::Dead);
+
+        // Dead pets no longer tick
+        let outcome = apply_time_elapsed(&mut state, &GameParams::default(), SUPPLY, 800_000 + 7 * 144);
+        assert_eq!(outcome, TickOutcome::default());
+    }
+
+    #[test]
+    fn test_caretaker_permissions() {
+        let mut state = test_pet(800_000);
+        let owner = Pubkey::new_unique();
+        let sitter = Pubkey::new_unique();
+        let stranger = Pubkey::new_unique();
+        state.owner = owner;
+
+        add_caretaker(&mut state, sitter, 800_144, CARE_FEED | CARE_CLEAN, 800_000).unwrap();
+
+        assert!(authorize_care(&state, &owner, CARE_MEDICINE, 800_010).is_ok());
+        assert!(authorize_care(&state, &sitter, CARE_FEED, 800_010).is_ok());
+        assert_eq!(
+            authorize_care(&state, &sitter, CARE_PLAY, 800_010),
+            Err(ProgramError::IllegalOwner)
+        );
+        assert!(authorize_care(&state, &stranger, CARE_FEED, 800_010).is_err());
+
+        // Delegation lapses at the expiry block
+        assert!(authorize_care(&state, &sitter, CARE_FEED, 800_144).is_err());
+
+        // The sitter pays the burn from their own $GOTCHI, never the owner's
+        let sitter_tokens = AccountBalance { owner: sitter, balance: 10 };
+        let owner_tokens = AccountBalance { owner, balance: 1_000 };
+        assert!(check_burn_payer(&sitter_tokens, &sitter, 10).is_ok());
+        assert_eq!(check_burn_payer(&owner_tokens, &sitter, 10), Err(ProgramError::IllegalOwner));
+        assert_eq!(check_burn_payer(&sitter_tokens, &sitter, 11), Err(ProgramError::InsufficientFunds));
+    }
+
+    #[test]
+    fn test_caretaker_limits() {
+        let mut state = test_pet(800_000);
+
+        assert_eq!(
+            add_caretaker(&mut state, Pubkey::new_unique(), 799_000, CARE_FEED, 800_000),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            add_caretaker(&mut state, Pubkey::new_unique(), 900_000, 0x80, 800_000),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        for _ in 0..MAX_CARETAKERS {
+            add_caretaker(&mut state, Pubkey::new_unique(), 900_000, CARE_ALL, 800_000).unwrap();
+        }
+        assert_eq!(
+            add_caretaker(&mut state, Pubkey::new_unique(), 900_000, CARE_ALL, 800_000),
+            Err(ProgramError::Custom(5))
+        );
+
+        // Expired sitters are pruned to make room
+        assert!(add_caretaker(&mut state, Pubkey::new_unique(), 1_000_000, CARE_ALL, 950_000).is_ok());
+        assert_eq!(state.caretakers.len(), 1);
+    }
+
+    #[test]
+    fn test_freeze_pauses_decay() {
+        let mut state = test_pet(800_000);
+        apply_freeze(&mut state, &GameParams::default(), SUPPLY, None, 1_440, 800_000).unwrap();
+
+        // Ten frozen days: no decay, no mistakes, no earnings
+        let outcome = apply_time_elapsed(&mut state, &GameParams::default(), SUPPLY, 801_440);
+        assert_eq!(outcome.frozen_blocks, 1_440);
+        assert_eq!(outcome.blocks_elapsed, 0);
+        assert_eq!(state.status, Status::Alive);
+        assert_eq!(state.health, 100);
+        assert_eq!(state.care_mistakes, 0);
+        assert_eq!(state.unclaimed_rewards, 0);
+        assert_eq!(state.last_fed_block, 801_440);
+        assert_eq!(state.evolution_eligible_block, 801_008 + 1_440);
+
+        // Time runs normally once the freeze ends
+        let outcome = apply_time_elapsed(&mut state, &GameParams::default(), SUPPLY, 801_440 + 288);
+        assert_eq!(outcome.frozen_blocks, 0);
+        assert_eq!(outcome.blocks_elapsed, 288);
+    }
+
+    #[test]
+    fn test_freeze_lifetime_cap() {
+        let mut state = test_pet(800_000);
+        apply_freeze(&mut state, &GameParams::default(), SUPPLY, None, MAX_LIFETIME_FROZEN_BLOCKS - 10, 800_000).unwrap();
+        assert_eq!(apply_freeze(&mut state, &GameParams::default(), SUPPLY, None, 11, 800_100), Err(ProgramError::Custom(7)));
+
+        // Extending an active freeze stacks on its end
+        apply_freeze(&mut state, &GameParams::default(), SUPPLY, None, 10, 800_100).unwrap();
+        assert_eq!(state.frozen_until_block, 800_000 + MAX_LIFETIME_FROZEN_BLOCKS);
+    }
+
+    #[test]
+    fn test_evolution_readiness() {
+        let mut state = test_pet(800_000);
+        assert!(!is_evolution_ready(&state, 801_007));
+        assert!(is_evolution_ready(&state, 801_008));
+
+        state.status = Status::Dead;
+        assert!(!is_evolution_ready(&state, 801_008));
+    }
+
+    #[test]
+    fn test_update_block_not_ahead_of_chain() {
+        assert!(check_update_block(800_000, 800_000).is_ok());
+        assert!(check_update_block(799_000, 800_000).is_ok());
+        assert_eq!(check_update_block(800_001, 800_000), Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn test_keeper_rewards() {
+        let mut keeper = KeeperState::new(Pubkey::new_unique());
+        let mut pets: Vec<SatsgotchiState> = (0..=MAX_KEEPER_REWARDS_PER_BLOCK).map(|_| test_pet(790_000)).collect();
+        let advanced = |blocks| TickOutcome {
+            blocks_elapsed: blocks,
+            ..TickOutcome::default()
+        };
+
+        // Too small an advance counts as an update but isn't paid
+        assert!(!credit_keeper(&mut keeper, &mut pets[0], &advanced(KEEPER_MIN_BLOCKS - 1), 800_000));
+        assert!(credit_keeper(&mut keeper, &mut pets[0], &advanced(KEEPER_MIN_BLOCKS), 800_000));
+        assert_eq!(keeper.total_updates, 2);
+        assert_eq!(keeper.unclaimed_rewards, KEEPER_REWARD);
+
+        // A pet pays once per KEEPER_MIN_BLOCKS, whichever keeper cranks it
+        let mut other_keeper = KeeperState::new(Pubkey::new_unique());
+        assert!(!credit_keeper(&mut other_keeper, &mut pets[0], &advanced(144), 800_000));
+        assert!(!credit_keeper(&mut keeper, &mut pets[0], &advanced(144), 800_000 + KEEPER_MIN_BLOCKS - 1));
+        assert_eq!(pets[0].last_keeper_reward_block, 800_000);
+
+        // Per-block cap, reset on the next block
+        for pet in pets.iter_mut().skip(1).take(MAX_KEEPER_REWARDS_PER_BLOCK as usize - 1) {
+            assert!(credit_keeper(&mut keeper, pet, &advanced(144), 800_000));
+        }
+        let last = pets.last_mut().unwrap();
+        assert!(!credit_keeper(&mut keeper, last, &advanced(144), 800_000));
+        assert!(credit_keeper(&mut keeper, last, &advanced(144), 800_001));
+        assert_eq!(keeper.rewarded_updates, MAX_KEEPER_REWARDS_PER_BLOCK as u64 + 1);
+    }
+
+    #[test]
+    fn test_split_updates_decay_like_one() {
+        let mut once = test_pet(800_000);
+        apply_time_elapsed(&mut once, &GameParams::default(), SUPPLY, 800_000 + 576);
+
+        let mut often = test_pet(800_000);
+        for block in (800_000..=800_000 + 576).step_by(36) {
+            apply_time_elapsed(&mut often, &GameParams::default(), SUPPLY, block);
+        }
+
+        assert_eq!(often.hunger, once.hunger);
+        assert_eq!(often.health, once.health);
+        assert_eq!(often.happiness, once.happiness);
+        assert_eq!(often.age_blocks, 576);
+
+        // One mistake per missed 24h feed window, however often it's cranked
+        assert_eq!(often.care_mistakes, once.care_mistakes);
+        assert_eq!(once.care_mistakes, 3);
+
+        // A meal starts counting windows afresh
+        apply_feed(&mut often, 800_576).unwrap();
+        apply_time_elapsed(&mut often, &GameParams::default(), SUPPLY, 800_576 + 144);
+        assert_eq!(often.care_mistakes, 3);
+        apply_time_elapsed(&mut often, &GameParams::default(), SUPPLY, 800_576 + 145);
+        assert_eq!(often.care_mistakes, 4);
+    }
+
+    #[test]
+    fn test_settle_before_action() {
+        let mut state = test_pet(800_000);
+
+        // Long-neglected pet dies on settlement instead of being fed
+        assert_eq!(settle_elapsed_time(&mut state, &GameParams::default(), SUPPLY, None, 800_000 + 10 * 144), Err(ProgramError::Custom(1)));
+        assert_eq!(state.status, Status::Dead);
+        assert_eq!(apply_evolve(&mut state, &GameParams::default(), 900_000), Err(ProgramError::Custom(1)));
+
+        let mut state = test_pet(800_000);
+        let outcome = settle_elapsed_time(&mut state, &GameParams::default(), SUPPLY, None, 800_000 + 144).unwrap();
+        assert_eq!(outcome.blocks_elapsed, 144);
+        assert_eq!(state.last_update_block, 800_144);
+    }
+
+    #[test]
+    fn test_care_cooldowns() {
+        let mut state = test_pet(800_000);
+        state.happiness = 0;
+
+        apply_play(&mut state, 800_010).unwrap();
+        assert_eq!(state.happiness, 20);
+
+        // Spamming in the same block is rejected
+        assert_eq!(apply_play(&mut state, 800_010), Err(ProgramError::Custom(8)));
+        assert_eq!(next_action_block(&state, CARE_PLAY), 800_010 + PLAY_COOLDOWN_BLOCKS);
+
+        // Repeats inside the window have diminishing returns
+        apply_play(&mut state, 800_016).unwrap();
+        apply_play(&mut state, 800_022).unwrap();
+        assert_eq!(state.happiness, 35);
+
+        // A new window restores full strength
+        apply_play(&mut state, 800_010 + CARE_WINDOW_BLOCKS).unwrap();
+        assert_eq!(state.happiness, 55);
+
+        // Cooldowns are tracked per action
+        apply_medicine(&mut state, 800_200).unwrap();
+        assert_eq!(apply_medicine(&mut state, 800_250), Err(ProgramError::Custom(8)));
+        apply_clean(&mut state, 800_250).unwrap();
+    }
+
+    #[test]
+    fn test_config_timelock() {
+        let admin = Pubkey::new_unique();
+        assert_eq!(
+            GameConfig::new(admin, admin, admin, Pubkey::new_unique(), MIN_CONFIG_TIMELOCK_BLOCKS - 1, GameParams::default()),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        let mut config = GameConfig::new(admin, admin, admin, Pubkey::new_unique(), 288, GameParams::default()).unwrap();
+        let mut faster = GameParams::default();
+        faster.baby.evolution_blocks = 144;
+        faster.baby.evolution_reward = 75;
+
+        // Invalid parameters are rejected outright
+        let mut broken = faster.clone();
+        broken.hunger_period_blocks = 0;
+        assert_eq!(propose_config_update(&mut config, broken, 800_000), Err(ProgramError::InvalidArgument));
+
+        // Queued parameters only apply once the timelock has passed
+        propose_config_update(&mut config, faster.clone(), 800_000).unwrap();
+        assert_eq!(config.active_params(800_287), &GameParams::default());
+        assert_eq!(config.active_params(800_288), &faster);
+
+        let mut state = test_pet(800_000);
+        state.evolution_eligible_block = 800_144;
+        assert_eq!(apply_evolve(&mut state, config.active_params(800_300), 800_300), Ok(75));
+        assert_eq!(state.evolution_eligible_block, 800_300 + faster.child.evolution_blocks);
+
+        // Too late to cancel an update that is already live
+        assert_eq!(cancel_config_update(&mut config, 800_300), Err(ProgramError::Custom(9)));
+        assert_eq!(config.params, faster);
+
+        propose_config_update(&mut config, GameParams::default(), 800_300).unwrap();
+        cancel_config_update(&mut config, 800_301).unwrap();
+        assert_eq!(config.active_params(900_000), &faster);
+    }
+
+    #[test]
+    fn test_pause_is_not_neglect() {
+        let admin = Pubkey::new_unique();
+        let mut config = GameConfig::new(admin, admin, admin, Pubkey::new_unique(), 288, GameParams::default()).unwrap();
+        let mut paused_pet = test_pet(800_000);
+        let mut control = test_pet(800_000);
+
+        // Paused for 10 days, which would kill an unfed baby
+        set_paused(&mut config, true, 800_100).unwrap();
+        assert_eq!(set_paused(&mut config, true, 800_200), Err(ProgramError::InvalidArgument));
+        assert_eq!(config.total_paused_blocks(800_500), 400);
+        set_paused(&mut config, false, 801_540).unwrap();
+
+        credit_paused_blocks(&mut paused_pet, config.total_paused_blocks(801_640));
+        let outcome = apply_time_elapsed(&mut paused_pet, &GameParams::default(), SUPPLY, 801_640);
+        assert!(!outcome.died);
+        assert_eq!(outcome.blocks_elapsed, 200);
+
+        // Crediting is idempotent
+        let fed_block = paused_pet.last_fed_block;
+        credit_paused_blocks(&mut paused_pet, config.total_paused_blocks(801_700));
+        assert_eq!(paused_pet.last_fed_block, fed_block);
+
+        // Deadlines move with the pause: cooldowns, sitters, poop age, the
+        // household bonus and the resale cooldown
+        let mut deadlines = test_pet(800_000);
+        let sitter = Pubkey::new_unique();
+        add_caretaker(&mut deadlines, sitter, 800_144, CARE_FEED, 800_000).unwrap();
+        apply_feed(&mut deadlines, 800_050).unwrap();
+        deadlines.poop_since_block = 800_060;
+        deadlines.household_bonus_until = 800_144;
+        deadlines.last_transfer_block = 800_010;
+        credit_paused_blocks(&mut deadlines, 1_440);
+        assert_eq!(deadlines.caretakers[0].expires_block, 800_144 + 1_440);
+        assert!(authorize_care(&deadlines, &sitter, CARE_FEED, 800_200 + 1_440).is_err());
+        assert!(authorize_care(&deadlines, &sitter, CARE_FEED, 800_100 + 1_440).is_ok());
+        assert_eq!(deadlines.cooldowns.feed.last_block, 800_050 + 1_440);
+        assert_eq!(next_action_block(&deadlines, CARE_FEED), 800_050 + 1_440 + cooldown_blocks(CARE_FEED));
+        assert_eq!(deadlines.poop_since_block, 800_060 + 1_440);
+        assert_eq!(deadlines.household_bonus_until, 800_144 + 1_440);
+        assert_eq!(deadlines.last_transfer_block, 800_010 + 1_440);
+        assert_eq!(deadlines.cooldowns.play, ActionCooldown::default()); // Never used stays unset
+
+        apply_time_elapsed(&mut control, &GameParams::default(), SUPPLY, 801_640);
+        assert_eq!(control.status, Status::Dead);
+
+        assert!(SatsgotchiInstruction::TransferOwnership { new_owner: admin }.allowed_while_paused());
+        assert!(!SatsgotchiInstruction::Feed.allowed_while_paused());
+    }
+
+    #[test]
+    fn test_transfer_settles_rewards() {
+        let params = GameParams::default();
+        let seller = Pubkey::new_unique();
+        let buyer = Pubkey::new_unique();
+        // Stays within one quest day so only passive earnings are settled
+        let mut state = test_pet(800_064);
+        state.owner = seller;
+
+        assert_eq!(apply_transfer(&mut state, &params, SUPPLY, None, buyer, 800_184), 600_000_000_000);
+        assert_eq!(state.owner, buyer);
+        assert_eq!(state.unclaimed_rewards, 0);
+        assert_eq!(state.settled_rewards, vec![SettledRewards { owner: seller, amount: 600_000_000_000 }]);
+
+        assert_eq!(take_settled_rewards(&mut state, &buyer), Err(ProgramError::Custom(4)));
+        assert_eq!(take_settled_rewards(&mut state, &seller), Ok(600_000_000_000));
+        assert!(state.settled_rewards.is_empty());
+
+        // Flipping it straight back still mirrors the sale...
+        apply_transfer(&mut state, &params, SUPPLY, None, seller, 800_300);
+        assert_eq!(state.owner, seller);
+        assert!(in_transfer_cooldown(&state, &params, 800_443));
+        assert!(!in_transfer_cooldown(&state, &params, 800_444));
+
+        // ...but a well-fed day inside the cooldown earns no care credit
+        apply_feed(&mut state, 800_300).unwrap();
+        apply_time_elapsed(&mut state, &params, SUPPLY, 800_443);
+        assert_eq!((state.perfect_care_days, state.current_streak_days), (0, 0));
+        apply_feed(&mut state, 800_444).unwrap();
+        apply_time_elapsed(&mut state, &params, SUPPLY, 800_587);
+        assert_eq!((state.perfect_care_days, state.current_streak_days), (1, 1));
+    }
+
+    #[test]
+    fn test_rewards_track_supply() {
+        // 0.00002% of supply per hour: 30 $GOTCHI/hour for a baby at 150M
+        let mut state = test_pet(800_000);
+        apply_time_elapsed(&mut state, &GameParams::default(), SUPPLY, 800_006);
+        assert_eq!(state.unclaimed_rewards, 30_000_000_000);
+
+        // Doubling the supply doubles the rate
+        apply_time_elapsed(&mut state, &GameParams::default(), 2 * SUPPLY, 800_012);
+        assert_eq!(state.unclaimed_rewards, 90_000_000_000);
+
+        // Fractional hours and dust carry over instead of being dropped
+        let odd_supply = SUPPLY + 7;
+        let mut once = test_pet(800_000);
+        apply_time_elapsed(&mut once, &GameParams::default(), odd_supply, 800_140);
+        let mut often = test_pet(800_000);
+        for block in (800_000..=800_140).step_by(5) {
+            apply_time_elapsed(&mut often, &GameParams::default(), odd_supply, block);
+        }
+        assert_eq!(often.unclaimed_rewards, once.unclaimed_rewards);
+        assert_eq!(often.reward_remainder, once.reward_remainder);
+    }
+
+    #[test]
+    fn test_achievements() {
+        let params = GameParams::default();
+        let mut state = test_pet(800_000);
+
+        // A month of daily care earns Perfect Month and its bonus once
+        let mut block = 800_000;
+        for _ in 0..30 {
+            block += 144;
+            apply_time_elapsed(&mut state, &params, SUPPLY, block);
+            state.last_fed_block = block;
+        }
+        assert_eq!(state.perfect_care_days, 30);
+        assert_eq!(state.badges, BADGE_PERFECT_MONTH);
+        assert_eq!(state.care_multiplier, 105);
+
+        // Evolving unlocks First Steps; reaching Senior cleanly unlocks Flawless Elder
+        state.evolution_eligible_block = block;
+        apply_evolve(&mut state, &params, block).unwrap();
+        assert_ne!(state.badges & BADGE_FIRST_EVOLUTION, 0);
+        state.level = Level::Adult;
+        state.evolution_eligible_block = block;
+        apply_evolve(&mut state, &params, block).unwrap();
+        assert_ne!(state.badges & BADGE_FLAWLESS_SENIOR, 0);
+        assert_eq!(state.care_multiplier, 115);
+
+        // Nothing unlocks twice
+        assert_eq!(unlock_achievements(&mut state), 0);
+        assert_eq!(state.care_multiplier, 115);
+    }
+
+    #[test]
+    fn test_daily_quests() {
+        let params = GameParams::default();
+
+        // Same set for everyone, three distinct quests, and it changes over time
+        assert_eq!(daily_quests(5_555), daily_quests(5_555));
+        let today = daily_quests(5_555);
+        assert!(today.iter().enumerate().all(|(i, q)| !today[..i].contains(q)));
+        assert!((0..10).any(|day| daily_quests(day) != today));
+
+        // Find a day with a Play quest and complete it
+        let day = (5_556..).find(|day| daily_quests(*day).contains(&Quest::Play { times: 3 })).unwrap();
+        let start = day * QUEST_DAY_BLOCKS;
+        let mut state = test_pet(start);
+        for i in 0..3 {
+            apply_play(&mut state, start + i * PLAY_COOLDOWN_BLOCKS).unwrap();
+        }
+        assert_eq!(settle_quests(&mut state, &params, start + 12), params.quest_reward);
+        assert_eq!(settle_quests(&mut state, &params, start + 13), 0); // Paid once
+
+        // All-day quests are judged after the day ends
+        let day = (5_556..).find(|day| daily_quests(*day).contains(&Quest::KeepHungerBelow { max: 50 })).unwrap();
+        let start = day * QUEST_DAY_BLOCKS;
+        let mut state = test_pet(start);
+        assert_eq!(settle_quests(&mut state, &params, start + 100), 0);
+        assert!(settle_quests(&mut state, &params, start + QUEST_DAY_BLOCKS) >= params.quest_reward);
+    }
+
+    #[test]
+    fn test_care_streaks() {
+        let params = GameParams::default();
+        let owner = Pubkey::new_unique();
+        let mut state = test_pet(800_064);
+        state.owner = owner;
+
+        // Ten days of daily feeding
+        let mut block = 800_064;
+        for _ in 0..10 {
+            block += 144;
+            apply_time_elapsed(&mut state, &params, SUPPLY, block);
+            state.last_fed_block = block;
+        }
+        assert_eq!(state.current_streak_days, 10);
+        assert_eq!(streak_bonus(&state, &params), 10);
+
+        // The streak bonus boosts earnings: 30 $GOTCHI/hour * 1.10
+        let earned_before = state.unclaimed_rewards;
+        apply_time_elapsed(&mut state, &params, SUPPLY, block + 6);
+        assert_eq!(state.unclaimed_rewards - earned_before, 33_000_000_000);
+
+        // A missed window ends the streak but keeps the record
+        apply_time_elapsed(&mut state, &params, SUPPLY, block + 2 * 144);
+        assert_eq!(state.current_streak_days, 0);
+        assert_eq!(state.longest_streak_days, 10);
+        assert_eq!(state.streak_history, vec![StreakRecord { owner, days: 10, ended_block: block + 2 * 144 }]);
+        assert_eq!(best_streak_for(&state, &owner), 10);
+        assert_eq!(best_streak_for(&state, &Pubkey::new_unique()), 0);
+    }
+
+    #[test]
+    fn test_bitcoin_events() {
+        let params = GameParams::default();
+        assert!(active_bitcoin_events(&params, 800_000).is_empty());
+        assert_eq!(active_bitcoin_events(&params, 840_000), vec![BitcoinEvent::Halving { epoch: 4 }]);
+        assert_eq!(active_bitcoin_events(&params, 800_352), vec![BitcoinEvent::DifficultyAdjustment { period: 397 }]);
+
+        // Double earnings only for the part of a tick inside the halving window
+        let mut state = test_pet(839_994);
+        state.last_fed_block = 840_000;
+        let outcome = apply_time_elapsed(&mut state, &params, SUPPLY, 840_006);
+        assert_eq!(outcome.bonus_blocks, 6);
+        assert_eq!(outcome.rewards_accrued, 30_000_000_000 + 2 * 30_000_000_000);
+        assert_eq!(outcome.accessory_dropped, Some(204));
+        assert_eq!(apply_time_elapsed(&mut state, &params, SUPPLY, 840_012).accessory_dropped, None);
+        assert_eq!(state.traits.accessories, vec![204]);
+
+        // Evolution opens a day early right after a difficulty adjustment
+        let retarget = 800_352; // 397 * 2016
+        let mut state = test_pet(800_000);
+        state.evolution_eligible_block = retarget + 100;
+        assert_eq!(apply_evolve(&mut state, &params, retarget - 1), Err(ProgramError::Custom(2)));
+        assert!(apply_evolve(&mut state, &params, retarget).is_ok());
+    }
+
+    #[test]
+    fn test_weather() {
+        let params = GameParams::default();
+        let reading = |weather, observed_block| WeatherState {
+            oracle: Pubkey::new_unique(),
+            readings: vec![WeatherReading { weather, observed_block }],
+        };
+
+        // A storm drains 1 extra happiness per 36 blocks of height, but only
+        // over the 6 blocks the reading covers (800_064 is a multiple of 36)
+        let storm = reading(Weather::Storm, 800_060);
+        let mut whole = test_pet(800_000);
+        assert_eq!(apply_tick(&mut whole, &params, SUPPLY, Some(&storm), 800_072).weather_happiness, -1);
+
+        // The same whether a crank splits the time or an action settles it
+        let mut split = test_pet(800_000);
+        for block in [800_030, 800_062, 800_065, 800_072] {
+            apply_tick(&mut split, &params, SUPPLY, Some(&storm), block);
+        }
+        assert_eq!(split.happiness, whole.happiness);
+        let mut acted = test_pet(800_000);
+        settle_elapsed_time(&mut acted, &params, SUPPLY, Some(&storm), 800_072).unwrap();
+        assert_eq!(acted.happiness, whole.happiness);
+
+        // A fresh storm doesn't reach back over a long-stale pet...
+        let mut stale = test_pet(800_000);
+        let late_storm = reading(Weather::Storm, 800_706);
+        assert_eq!(apply_tick(&mut stale, &params, SUPPLY, Some(&late_storm), 800_712).weather_happiness, -1);
+        // ...and an old reading doesn't touch later blocks
+        assert_eq!(apply_tick(&mut stale, &params, SUPPLY, Some(&late_storm), 800_790).weather_happiness, 0);
+
+        // Quiet blocks cheer the pet up
+        let mut state = test_pet(800_000);
+        state.happiness = 50;
+        let outcome = apply_tick(&mut state, &params, SUPPLY, Some(&reading(Weather::Quiet, 800_060)), 800_072);
+        assert_eq!(outcome.weather_happiness, 1);
+        assert_eq!(state.happiness, 51);
+
+        // Every reading published between two ticks applies, each until the next
+        let mut history = reading(Weather::Storm, 800_060);
+        history.push(WeatherReading { weather: Weather::Quiet, observed_block: 800_062 }).unwrap();
+        history.push(WeatherReading { weather: Weather::Quiet, observed_block: 800_134 }).unwrap();
+        assert_eq!(
+            history.push(WeatherReading { weather: Weather::Storm, observed_block: 800_134 }),
+            Err(ProgramError::InvalidArgument)
+        );
+        let mut state = test_pet(800_000);
+        state.happiness = 50;
+        // The first quiet reading cuts the storm short of 800_064 and covers
+        // it itself; the later one covers 800_136 (both multiples of 72)
+        let outcome = apply_tick(&mut state, &params, SUPPLY, Some(&history), 800_200);
+        assert_eq!(outcome.weather_happiness, 2);
+
+        // The history keeps only the latest MAX_WEATHER_READINGS
+        let mut full = reading(Weather::Normal, 1);
+        for block in 2..=MAX_WEATHER_READINGS as u64 + 1 {
+            full.push(WeatherReading { weather: Weather::Normal, observed_block: block }).unwrap();
+        }
+        assert_eq!(full.readings.len(), MAX_WEATHER_READINGS);
+        assert_eq!(full.readings[0].observed_block, 2);
+    }
+
+    #[test]
+    fn test_species_and_traits() {
+        let params = GameParams::default();
+        let traits = |pet_type, rarity, color_shift| Traits { rarity, color_shift, pet_type, accessories: Vec::new() };
+
+        assert!(traits(3, 4, 360).validate().is_ok());
+        assert_eq!(traits(0, 5, 0).validate(), Err(ProgramError::InvalidArgument));
+        assert_eq!(traits(0, 0, 361).validate(), Err(ProgramError::InvalidArgument));
+        assert_eq!(traits(SPECIES.len() as u8, 0, 0).validate(), Err(ProgramError::InvalidArgument));
+
+        // Common Satoshi plays by the config exactly
+        assert_eq!(species_params(&params, &traits(0, 0, 0)), params);
+
+        // Miners get hungry faster and earn more; Hodlers take longer to grow
+        let miner = species_params(&params, &traits(1, 0, 0));
+        assert_eq!(miner.hunger_period_blocks, params.hunger_period_blocks * 100 / 125);
+        assert_eq!(miner.baby.reward_rate_ppb, 240);
+        let hodler = SatsgotchiState::new("h".to_string(), Pubkey::new_unique(), traits(2, 0, 0), &params, 800_000);
+        assert_eq!(hodler.evolution_eligible_block, 800_000 + params.baby.evolution_blocks * 120 / 100);
+
+        // A legendary Satoshi earns 20% more and decays 12% slower
+        let legendary = species_params(&params, &traits(0, 4, 0));
+        assert_eq!(legendary.baby.reward_rate_ppb, 240);
+        assert_eq!(legendary.happiness_period_blocks, 144 * 100 / 88);
+    }
+
+    #[test]
+    fn test_inscription_traits() {
+        let txid = "6fb976ab49dcec017f1e201e84395983204ae1a7c2abf7ced0a85d692e442799";
+        let id = format!("{}i0", txid);
+
+        assert_eq!(parse_inscription_id(&id).unwrap().1, 0);
+        assert_eq!(parse_inscription_id(&format!("{}i42", txid)).unwrap().1, 42);
+        for bad in [
+            txid.to_string(),                                 // no index
+            format!("{}i", txid),                             // empty index
+            format!("{}i01", txid),                           // leading zero
+            format!("{}i0", txid.to_uppercase()),             // not canonical hex
+            format!("{}i0", &txid[1..]),                      // short txid
+            format!("{}gi0", &txid[1..]),                     // not hex
+        ] {
+            assert_eq!(parse_inscription_id(&bad), Err(ProgramError::InvalidArgument), "{}", bad);
+        }
+
+        // Same inscription, same traits; always in range
+        let traits = derive_traits(&id).unwrap();
+        assert_eq!(derive_traits(&id).unwrap(), traits);
+        assert!(traits.validate().is_ok() && traits.accessories.is_empty());
+
+        // Legendaries stay rare across many inscriptions
+        let legendary = (0..10_000)
+            .filter(|i| derive_traits(&format!("{}i{}", txid, i)).unwrap().rarity == MAX_RARITY)
+            .count();
+        assert!((50..200).contains(&legendary), "{}", legendary);
+    }
+
+    #[test]
+    fn test_body_condition() {
+        let mut state = test_pet(800_000);
+        assert_eq!(vitality_score(&state), 90); // Full meters, fitness 50
+
+        // Meals and snacks add weight, play burns it and builds fitness
+        apply_feed(&mut state, 800_000).unwrap();
+        apply_play(&mut state, 800_000).unwrap();
+        assert_eq!((state.weight, state.fitness), (BIRTH_WEIGHT, BIRTH_FITNESS + 5));
+        apply_snack(&mut state, 800_012).unwrap();
+        assert_eq!(state.weight, BIRTH_WEIGHT + SNACK_WEIGHT_GAIN);
+        assert_eq!(state.last_fed_block, 800_000); // a snack isn't a meal
+        assert_eq!(apply_snack(&mut state, 800_013), Err(ProgramError::Custom(8))); // shares the feed cooldown
+
+        // An overweight couch potato is likely to get sick and dies of neglect sooner
+        state.weight = IDEAL_WEIGHT_MAX + 15;
+        state.fitness = 0;
+        assert_eq!(sickness_chance_pct(&state), 1 + 30 + 10);
+        assert!(is_frail(&state));
+        assert!(vitality_score(&state) < 70);
+        assert!(should_die(&state, &GameParams::default(), 800_000 + 5 * 144));
+        assert!(!should_die(&test_pet(800_000), &GameParams::default(), 800_000 + 5 * 144));
+
+        // Sickness rolls are per day of age, so they don't depend on update timing
+        let mut params = GameParams::default();
+        params.baby.death_neglect_days = 100;
+        let mut whole = state.clone();
+        let mut split = state.clone();
+        apply_time_elapsed(&mut whole, &params, SUPPLY, 800_000 + 10 * 144);
+        for day in 1..=10 {
+            apply_time_elapsed(&mut split, &params, SUPPLY, 800_000 + day * 144);
+        }
+        assert!(whole.sick);
+        assert_eq!(split.sick, whole.sick);
+
+        // Pets born in the same block don't share their rolls
+        let mut twin = state.clone();
+        twin.inscription_id = "other-inscription".to_string();
+        let rolls = |pet: &SatsgotchiState| (1..=30).map(|day| rolls_sick(pet, day)).collect::<Vec<_>>();
+        assert_ne!(rolls(&state), rolls(&twin));
+    }
+
+    #[test]
+    fn test_event_history() {
+        let params = GameParams::default();
+        let mut state = test_pet(800_000);
+        state.hunger = 60;
+
+        apply_feed(&mut state, 800_000).unwrap();
+        assert_eq!(state.history.timeline(), vec![PetEvent {
+            kind: PetEventKind::Feed,
+            block: 800_000,
+            health_delta: 0,
+            happiness_delta: 0,
+            hunger_delta: -50,
+            care_mistakes: 0,
+        }]);
+
+        // Neglect shows up with the decay that came with it
+        apply_time_elapsed(&mut state, &params, SUPPLY, 800_000 + 2 * 144);
+        let neglect = state.history.timeline()[1].clone();
+        assert_eq!((neglect.kind, neglect.care_mistakes, neglect.hunger_delta), (PetEventKind::CareMistake, 1, 2));
+
+        // Full buffer keeps the newest HISTORY_LEN events in order
+        for i in 0..HISTORY_LEN as u64 {
+            apply_play(&mut state, 800_400 + i * 6).unwrap();
+        }
+        let timeline = state.history.timeline();
+        assert_eq!(timeline.len(), HISTORY_LEN);
+        assert!(timeline.iter().all(|e| e.kind == PetEventKind::Play));
+        assert!(timeline.windows(2).all(|w| w[0].block < w[1].block));
+    }
+
+    #[test]
+    fn test_quote_and_forecast() {
+        let params = GameParams::default();
+
+        // Burns scale with circulating supply, straight from the token program
+        let quote = quote_burns(&params, SUPPLY);
+        assert_eq!(quote.feed, calculate_dynamic_burn("feed_meal", SUPPLY));
+        assert_eq!((quote.feed, quote.snack, quote.medicine), (150_000_000, 90_000_000, 300_000_000));
+        assert_eq!(quote_burns(&params, 2 * SUPPLY).feed, 2 * quote.feed);
+
+        // A newborn left alone: first mistake the block after the 24h feed window,
+        // dead once unfed for more than 5 days, evolves after 7 days
+        let state = test_pet(800_000);
+        let forecast = forecast(&state, &params, 800_000);
+        assert_eq!(forecast.next_care_mistake_block, Some(800_000 + 144 + 1));
+        assert_eq!(forecast.death_block, Some(800_000 + 6 * 144));
+        assert_eq!(forecast.next_evolution_block, Some(800_000 + 1_008));
+        assert_eq!(state.history, test_pet(800_000).history); // untouched
+
+        // Nothing left to forecast for the dead
+        let mut dead = test_pet(800_000);
+        dead.status = Status::Dead;
+        assert_eq!(super::forecast(&dead, &params, 800_000), Forecast::default());
+    }
+
+    #[test]
+    fn test_household() {
+        let params = GameParams::default();
+        let owner = Pubkey::new_unique();
+        let mut household = Household {
+            owner,
+            pets: Vec::new(),
+            pets_adopted: 3,
+            total_care_actions: 0,
+            alive_pets: 0,
+            total_earned: 0,
+            updated_block: 0,
+        };
+        let mut pets = vec![test_pet(800_000), test_pet(800_000), test_pet(800_000)];
+        pets[2].status = Status::Dead;
+        pets[2].total_earned = 7;
+
+        // Same care for every pet; unknown actions are rejected
+        for pet in pets.iter_mut().take(2) {
+            apply_care(pet, CARE_PLAY, 800_000).unwrap();
+        }
+        assert_eq!(apply_care(&mut pets[0], CARE_ALL, 800_006), Err(ProgramError::InvalidArgument));
+
+        // Two living pets: +2 for the living ones, capped at +10 for big households
+        refresh_household(&mut household, &params, &mut pets, 800_000);
+        assert_eq!((household.alive_pets, household.total_earned), (2, 7));
+        assert_eq!((pets[0].household_bonus, pets[2].household_bonus), (2, 0));
+        assert_eq!(household_bonus(&params, 8), 10);
+
+        // The bonus adds to the earnings multiplier: 30 $GOTCHI/hour * 1.02
+        let outcome = apply_time_elapsed(&mut pets[0], &params, SUPPLY, 800_006);
+        assert_eq!(outcome.rewards_accrued, 30_600_000_000);
+
+        // It holds until the first block a living pet could die unattended
+        // (fed at 800_000, and a frail senior starves after a day), so a
+        // death the other pets never hear of can't leave it behind
+        assert_eq!(pets[1].household_bonus_until, 800_144);
+        assert_eq!(earliest_death_block(&pets[1], &params), 800_144);
+        let outcome = apply_time_elapsed(&mut pets[1], &params, SUPPLY, 800_150);
+        assert_eq!(outcome.rewards_accrued, 24 * 30_600_000_000 + 30_000_000_000);
+        assert_eq!(pets[1].household_bonus, 0);
+
+        // A sold pet leaves its bonus behind
+        apply_transfer(&mut pets[0], &params, SUPPLY, None, Pubkey::new_unique(), 800_012);
+        assert_eq!(pets[0].household_bonus, 0);
+    }
+
+    #[test]
+    fn test_pet_names() {
+        let params = GameParams::default();
+
+        // Case-insensitive keys, so "Satoshi" and "SATOSHI" share a record
+        assert_eq!(normalize_name("Lil Sats-21").unwrap(), "lil sats-21");
+        assert_eq!(normalize_name("Satoshi"), normalize_name("SATOSHI"));
+        assert_ne!(name_address(&Pubkey::default(), "satoshi"), name_address(&Pubkey::default(), "hal"));
+
+        // Length and charset limits
+        for bad in ["ab", "a_very_long_pet_name", " sats", "sats ", "two  spaces", "sats!", "säts"] {
+            assert_eq!(normalize_name(bad), Err(ProgramError::InvalidArgument), "{:?}", bad);
+        }
+
+        // A dead pet keeps its name for the grace period
+        let mut state = test_pet(800_000);
+        assert!(!is_name_releasable(&state, &params, 900_000));
+        apply_time_elapsed(&mut state, &params, SUPPLY, 800_000 + 6 * 144);
+        assert_eq!(state.status, Status::Dead);
+        assert_eq!(state.died_block, 800_000 + 6 * 144);
+        assert!(!is_name_releasable(&state, &params, state.died_block + 1_007));
+        assert!(is_name_releasable(&state, &params, state.died_block + 1_008));
+
+        assert_eq!(quote_burns(&params, SUPPLY).rename, 25_000_000_000);
+    }
+
+    #[test]
+    fn test_visits_and_friendship() {
+        let params = GameParams::default();
+        let (visitor_key, host_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut visitor = test_pet(800_000);
+        let mut host = test_pet(800_000);
+        host.owner = Pubkey::new_unique();
+        visitor.happiness = 50;
+        host.happiness = 50;
+
+        // One pair, one account, whoever visits
+        let program_id = Pubkey::new_unique();
+        assert_eq!(friendship_address(&program_id, &visitor_key, &host_key),
+            friendship_address(&program_id, &host_key, &visitor_key));
+        let mut friendship = Friendship::new(host_key, visitor_key);
+        assert_eq!(friendship.pets, Friendship::new(visitor_key, host_key).pets);
+
+        // First visit: level 1, both pets +12 happiness
+        assert_eq!(apply_visit(&mut friendship, &mut visitor, visitor_key, &mut host, &params, 800_000), Ok(12));
+        assert_eq!((visitor.happiness, host.happiness, friendship.level), (62, 62, 1));
+        assert_eq!(host.history.timeline().last().unwrap().kind, PetEventKind::Visit);
+
+        // Once per pair per day
+        assert_eq!(apply_visit(&mut friendship, &mut host, host_key, &mut visitor, &params, 800_143),
+            Err(ProgramError::Custom(8)));
+
+        // Daily visits for a month: best friends, with a badge for both
+        for day in 1..30 {
+            apply_visit(&mut friendship, &mut host, host_key, &mut visitor, &params, 800_000 + day * 144).unwrap();
+        }
+        assert_eq!((friendship.visits, friendship.level), (30, MAX_FRIENDSHIP_LEVEL));
+        assert_eq!(friendship.log.len(), MAX_VISIT_LOG);
+        assert_eq!(friendship.log.last().unwrap().visitor, host_key);
+        assert_eq!(unlock_achievements(&mut visitor), BADGE_BEST_FRIENDS);
+        assert_eq!(visitor.care_multiplier, 105);
+
+        // Not with your own pets, nor with the dead
+        host.owner = visitor.owner;
+        assert_eq!(apply_visit(&mut friendship, &mut visitor, visitor_key, &mut host, &params, 810_000),
+            Err(ProgramError::InvalidArgument));
+        host.status = Status::Dead;
+        assert_eq!(apply_visit(&mut friendship, &mut visitor, visitor_key, &mut host, &params, 810_000),
+            Err(ProgramError::Custom(1)));
     }
 }
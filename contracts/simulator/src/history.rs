@@ -0,0 +1,159 @@
+// SATSGOTCHI PET HISTORY
+// Decodes a pet account and prints its recent event timeline, so support
+// can see what happened before a pet got sick or died
+//
+// Usage:
+//   pet-history --account pet.bin --format csv
+//
+// Options:
+//   --account  file with the raw pet account data  (required)
+//   --format   json | csv                           (default json)
+
+use borsh::BorshDeserialize;
+use serde::Serialize;
+
+use satsgotchi::{PetEvent, SatsgotchiState};
+use satsgotchi_simulator::{to_csv, Args, OutputFormat, Result, SimError, BLOCKS_PER_DAY};
+
+// ============================================================================
+// TIMELINE
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+struct TimelineEntry {
+    block: u64,
+    /// Day of the pet's life (0 = born that day)
+    day: u64,
+    event: String,
+    health_delta: i8,
+    happiness_delta: i8,
+    hunger_delta: i8,
+    care_mistakes: u8,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct PetTimeline {
+    inscription_id: String,
+    level: String,
+    status: String,
+    birth_block: u64,
+    last_update_block: u64,
+    care_mistakes: u8,
+    sick: bool,
+    events: Vec<TimelineEntry>,
+}
+
+/// Decode raw pet account data (trailing padding is ignored)
+fn decode_pet(data: &[u8]) -> Result<SatsgotchiState> {
+    SatsgotchiState::deserialize(&mut &data[..])
+        .map_err(|e| SimError::InvalidArgument(format!("not a pet account: {}", e)))
+}
+
+fn timeline(state: &SatsgotchiState) -> PetTimeline {
+    let entry = |event: &PetEvent| TimelineEntry {
+        block: event.block,
+        day: event.block.saturating_sub(state.birth_block) / BLOCKS_PER_DAY,
+        event: format!("{:?}", event.kind),
+        health_delta: event.health_delta,
+        happiness_delta: event.happiness_delta,
+        hunger_delta: event.hunger_delta,
+        care_mistakes: event.care_mistakes,
+    };
+
+    PetTimeline {
+        inscription_id: state.inscription_id.clone(),
+        level: format!("{:?}", state.level),
+        status: format!("{:?}", state.status),
+        birth_block: state.birth_block,
+        last_update_block: state.last_update_block,
+        care_mistakes: state.care_mistakes,
+        sick: state.sick,
+        events: state.history.timeline().iter().map(entry).collect(),
+    }
+}
+
+fn render_csv(timeline: &PetTimeline) -> String {
+    let header = [
+        "block",
+        "day",
+        "event",
+        "health_delta",
+        "happiness_delta",
+        "hunger_delta",
+        "care_mistakes",
+    ];
+    let rows: Vec<Vec<String>> = timeline
+        .events
+        .iter()
+        .map(|e| {
+            vec![
+                e.block.to_string(),
+                e.day.to_string(),
+                e.event.clone(),
+                e.health_delta.to_string(),
+                e.happiness_delta.to_string(),
+                e.hunger_delta.to_string(),
+                e.care_mistakes.to_string(),
+            ]
+        })
+        .collect();
+
+    to_csv(&header, &rows)
+}
+
+// ============================================================================
+// MAIN ENTRY POINT
+// ============================================================================
+
+fn main() -> Result<()> {
+    let args = Args::from_env()?;
+    let path: String = args.get("account", String::new())?;
+    if path.is_empty() {
+        return Err(SimError::InvalidArgument("--account is required".to_string()));
+    }
+    let format = args.get("format", OutputFormat::Json)?;
+
+    let state = decode_pet(&std::fs::read(path)?)?;
+    let timeline = timeline(&state);
+
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&timeline)?),
+        OutputFormat::Csv => print!("{}", render_csv(&timeline)),
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use borsh::BorshSerialize;
+    use satsgotchi::{
+        apply_feed, apply_time_elapsed, arch_program::pubkey::Pubkey, GameParams, Traits,
+    };
+    use satsgotchi_simulator::{LAUNCH_SUPPLY, START_BLOCK};
+
+    #[test]
+    fn test_decodes_account_timeline() {
+        let traits = Traits { rarity: 0, color_shift: 0, pet_type: 0, accessories: Vec::new() };
+        let params = GameParams::default();
+        let mut state = SatsgotchiState::new("pet".to_string(), Pubkey::default(), traits, &params, START_BLOCK);
+        apply_feed(&mut state, START_BLOCK + 6).unwrap();
+        apply_time_elapsed(&mut state, &params, LAUNCH_SUPPLY, START_BLOCK + 10 * BLOCKS_PER_DAY);
+
+        // Accounts are usually allocated with room to spare
+        let mut data = state.try_to_vec().unwrap();
+        data.resize(data.len() + 64, 0);
+
+        let timeline = timeline(&decode_pet(&data).unwrap());
+        let events: Vec<&str> = timeline.events.iter().map(|e| e.event.as_str()).collect();
+        assert_eq!(events, ["Feed", "CareMistake", "Died"]);
+        assert_eq!(timeline.events[2].day, 10);
+        assert!(render_csv(&timeline).starts_with("block,day,event,"));
+        assert!(decode_pet(&data[..10]).is_err());
+    }
+}
@@ -0,0 +1,428 @@
+// SATSGOTCHI SIMULATORS
+// Shared plumbing for the off-chain balance simulators
+// Game rules come from the program crates - nothing is re-implemented here
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde::Serialize;
+use thiserror::Error;
+
+use satsgotchi::{
+    apply_clean, apply_evolve, apply_feed, apply_medicine, apply_play, apply_time_elapsed,
+    derive_traits, settle_elapsed_time, settle_quests,
+    arch_program::pubkey::Pubkey, GameParams, Level, SatsgotchiState, Status, TickOutcome,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+pub const BLOCKS_PER_DAY: u64 = 144;
+pub const BLOCKS_PER_HOUR: u64 = 6;
+pub const START_BLOCK: u64 = 800_000;
+
+// 150M tokens (liquidity allocation) with 9 decimals
+pub const LAUNCH_SUPPLY: u64 = 150_000_000_000_000_000;
+
+// Report labels for `Traits.rarity`
+pub const RARITY_NAMES: [&str; 5] = ["common", "uncommon", "rare", "epic", "legendary"];
+
+// Mixed population split (percent)
+const MIXED_DILIGENT_PCT: u64 = 20;
+const MIXED_CASUAL_PCT: u64 = 50;
+
+// ============================================================================
+// ERROR TYPES
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum SimError {
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+pub type Result<T> = std::result::Result<T, SimError>;
+
+// ============================================================================
+// RANDOMNESS
+// ============================================================================
+
+/// Deterministic xorshift64* generator so every run is reproducible from a seed
+#[derive(Debug, Clone)]
+pub struct SimRng {
+    state: u64,
+}
+
+impl SimRng {
+    pub fn new(seed: u64) -> Self {
+        // xorshift must never be seeded with zero
+        Self { state: seed.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Uniform value in `0..bound` (bound must be non-zero)
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+
+    /// True with probability `bps` / 10,000
+    pub fn chance_bps(&mut self, bps: u32) -> bool {
+        self.below(10_000) < bps as u64
+    }
+}
+
+// ============================================================================
+// OWNER BEHAVIOUR
+// ============================================================================
+
+/// How an owner looks after their pet
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OwnerProfile {
+    Diligent,
+    Casual,
+    Absentee,
+}
+
+impl OwnerProfile {
+    pub const ALL: [OwnerProfile; 3] = [
+        OwnerProfile::Diligent,
+        OwnerProfile::Casual,
+        OwnerProfile::Absentee,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            OwnerProfile::Diligent => "diligent",
+            OwnerProfile::Casual => "casual",
+            OwnerProfile::Absentee => "absentee",
+        }
+    }
+
+    /// Times per day the owner opens the app
+    fn checkins_per_day(&self) -> u64 {
+        match self {
+            OwnerProfile::Diligent => 3,
+            OwnerProfile::Casual => 2,
+            OwnerProfile::Absentee => 1,
+        }
+    }
+
+    /// Chance (bps) that a check-in actually happens
+    fn care_chance_bps(&self) -> u32 {
+        match self {
+            OwnerProfile::Diligent => 9_700,
+            OwnerProfile::Casual => 7_500,
+            OwnerProfile::Absentee => 4_000,
+        }
+    }
+
+    /// Daily chance (bps) that the owner loses interest for good
+    fn abandon_chance_bps(&self) -> u32 {
+        match self {
+            OwnerProfile::Diligent => 5,
+            OwnerProfile::Casual => 30,
+            OwnerProfile::Absentee => 100,
+        }
+    }
+
+    /// Resolve a `--profile` value; "mixed" draws from the population split
+    pub fn pick(selection: &str, rng: &mut SimRng) -> Result<Self> {
+        match selection {
+            "diligent" => Ok(OwnerProfile::Diligent),
+            "casual" => Ok(OwnerProfile::Casual),
+            "absentee" => Ok(OwnerProfile::Absentee),
+            "mixed" => {
+                let roll = rng.below(100);
+                Ok(if roll < MIXED_DILIGENT_PCT {
+                    OwnerProfile::Diligent
+                } else if roll < MIXED_DILIGENT_PCT + MIXED_CASUAL_PCT {
+                    OwnerProfile::Casual
+                } else {
+                    OwnerProfile::Absentee
+                })
+            }
+            other => Err(SimError::InvalidArgument(format!("unknown profile: {}", other))),
+        }
+    }
+}
+
+// ============================================================================
+// PET SIMULATION
+// ============================================================================
+
+/// Everything that happened to a pet during one simulated hour
+#[derive(Debug, Clone, Default)]
+pub struct HourActions {
+    pub tick: Option<TickOutcome>,
+    pub fed: bool,
+    pub played: bool,
+    pub cleaned: bool,
+    pub medicine: bool,
+    /// Level the pet evolved from, if it evolved this hour
+    pub evolved_from: Option<Level>,
+}
+
+/// One pet and its owner, driven through the real game engine an hour at a time
+#[derive(Debug, Clone)]
+pub struct PetSim {
+    pub state: SatsgotchiState,
+    pub profile: OwnerProfile,
+    params: GameParams,
+    abandoned: bool,
+    checkin_hours: Vec<u64>,
+}
+
+impl PetSim {
+    pub fn new(profile: OwnerProfile, index: u64, birth_block: u64) -> Self {
+        // Species and rarity come from the inscription id, as on-chain
+        let inscription_id = format!("{:064x}i0", index);
+        let traits = derive_traits(&inscription_id).expect("simulated inscription ids are well-formed");
+        // Launch balance; rebalancing experiments can swap these out
+        let params = GameParams::default();
+        let state = SatsgotchiState::new(
+            inscription_id,
+            Pubkey::default(),
+            traits,
+            &params,
+            birth_block,
+        );
+
+        Self {
+            state,
+            profile,
+            params,
+            abandoned: false,
+            checkin_hours: Vec::new(),
+        }
+    }
+
+    /// Still worth simulating (not dead, not ascended)
+    pub fn is_active(&self) -> bool {
+        self.state.status == Status::Alive && self.state.level != Level::Ascended
+    }
+
+    /// Advance to `block` (one hour after the previous step): run the keeper
+    /// crank every `crank_blocks` and let the owner check in if it is time.
+    /// Earnings accrue against `circulating_supply`.
+    pub fn step_hour(
+        &mut self,
+        block: u64,
+        crank_blocks: u64,
+        circulating_supply: u64,
+        rng: &mut SimRng,
+    ) -> HourActions {
+        let mut actions = HourActions::default();
+        let age = block - self.state.birth_block;
+        let hour_of_day = age % BLOCKS_PER_DAY / BLOCKS_PER_HOUR;
+
+        // Plan the day: check-ins spread over waking hours (08:00-22:00) with jitter
+        if hour_of_day == 0 || self.checkin_hours.is_empty() {
+            if rng.chance_bps(self.profile.abandon_chance_bps()) {
+                self.abandoned = true;
+            }
+            let n = self.profile.checkins_per_day();
+            self.checkin_hours = (0..n).map(|i| 8 + i * 14 / n + rng.below(2)).collect();
+        }
+
+        // Keeper crank
+        if age.is_multiple_of(crank_blocks) {
+            actions.tick = Some(apply_time_elapsed(&mut self.state, &self.params, circulating_supply, block));
+        }
+
+        if !self.is_active() || self.abandoned || !self.checkin_hours.contains(&hour_of_day) {
+            return actions;
+        }
+        if !rng.chance_bps(self.profile.care_chance_bps()) {
+            return actions;
+        }
+
+        // Every action settles pending decay first, exactly like the program
+        if settle_elapsed_time(&mut self.state, &self.params, circulating_supply, None, block).is_err() {
+            return actions;
+        }
+
+        // Care routine; every action is legal on a living pet
+        actions.fed = apply_feed(&mut self.state, block).is_ok();
+        actions.played = apply_play(&mut self.state, block).is_ok();
+        if self.state.poop_count > 0 {
+            actions.cleaned = apply_clean(&mut self.state, block).is_ok();
+        }
+        if self.state.sick || self.state.health < 40 {
+            actions.medicine = apply_medicine(&mut self.state, block).is_ok();
+        }
+        if block >= self.state.evolution_eligible_block {
+            let level = self.state.level.clone();
+            if apply_evolve(&mut self.state, &self.params, block).is_ok() {
+                actions.evolved_from = Some(level);
+            }
+        }
+        settle_quests(&mut self.state, &self.params, block);
+
+        actions
+    }
+}
+
+// ============================================================================
+// COMMAND LINE
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = SimError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            other => Err(SimError::InvalidArgument(format!("unknown format: {}", other))),
+        }
+    }
+}
+
+/// Minimal `--key value` argument parser (the simulators only take scalars)
+#[derive(Debug, Default)]
+pub struct Args {
+    values: HashMap<String, String>,
+}
+
+impl Args {
+    pub fn from_env() -> Result<Self> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self> {
+        let mut values = HashMap::new();
+        let mut iter = args.into_iter();
+
+        while let Some(arg) = iter.next() {
+            let key = arg.strip_prefix("--").ok_or_else(|| {
+                SimError::InvalidArgument(format!("expected --flag, got {}", arg))
+            })?;
+            let value = iter.next().ok_or_else(|| {
+                SimError::InvalidArgument(format!("missing value for --{}", key))
+            })?;
+            values.insert(key.to_string(), value);
+        }
+
+        Ok(Self { values })
+    }
+
+    /// Parsed value for `--key`, or `default` when the flag is absent
+    pub fn get<T: FromStr>(&self, key: &str, default: T) -> Result<T> {
+        match self.values.get(key) {
+            Some(raw) => raw.parse().map_err(|_| {
+                SimError::InvalidArgument(format!("invalid value for --{}: {}", key, raw))
+            }),
+            None => Ok(default),
+        }
+    }
+}
+
+// ============================================================================
+// OUTPUT
+// ============================================================================
+
+/// Render a header and rows as CSV (values are plain numbers and identifiers)
+pub fn to_csv(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut out = header.join(",");
+    out.push('\n');
+    for row in rows {
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// `part / whole` as a percentage, 0 when `whole` is 0
+pub fn percent(part: u64, whole: u64) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 * 100.0 / whole as f64
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_reproducible() {
+        let mut a = SimRng::new(42);
+        let mut b = SimRng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_args_parsing() {
+        let args = Args::parse(
+            ["--pets", "500", "--format", "csv"].iter().map(|s| s.to_string()),
+        ).unwrap();
+
+        assert_eq!(args.get("pets", 10u32).unwrap(), 500);
+        assert_eq!(args.get("seed", 7u64).unwrap(), 7);
+        assert_eq!(args.get("format", OutputFormat::Json).unwrap(), OutputFormat::Csv);
+        assert!(Args::parse(["pets".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_pet_sim_feeds_and_ticks() {
+        let mut rng = SimRng::new(3);
+        let mut pet = PetSim::new(OwnerProfile::Diligent, 0, START_BLOCK);
+        let mut fed = false;
+
+        for hour in 1..=24 {
+            let block = START_BLOCK + hour * BLOCKS_PER_HOUR;
+            let actions = pet.step_hour(block, BLOCKS_PER_DAY, LAUNCH_SUPPLY, &mut rng);
+            fed |= actions.fed;
+            if hour == 24 {
+                assert!(actions.tick.is_some());
+            }
+        }
+
+        assert!(fed);
+        assert_eq!(pet.state.last_update_block, START_BLOCK + BLOCKS_PER_DAY);
+    }
+
+    #[test]
+    fn test_pet_sim_traits_follow_inscription() {
+        let pets: Vec<PetSim> = (0..64).map(|i| PetSim::new(OwnerProfile::Casual, i, START_BLOCK)).collect();
+
+        assert_eq!(pets[5].state.traits, derive_traits(&pets[5].state.inscription_id).unwrap());
+        assert!(pets.iter().any(|pet| pet.state.traits.pet_type != pets[0].state.traits.pet_type));
+        assert!(pets.iter().any(|pet| pet.state.traits.rarity > 0));
+    }
+
+    #[test]
+    fn test_csv_rendering() {
+        let csv = to_csv(&["a", "b"], &[vec!["1".into(), "2".into()]]);
+        assert_eq!(csv, "a,b\n1,2\n");
+    }
+}
@@ -0,0 +1,372 @@
+// SATSGOTCHI LIFECYCLE SIMULATOR
+// Runs a population of pets through the real game engine to check the
+// promised 99.5% death rate and 0.05% ascension rate
+//
+// Usage:
+//   lifecycle-sim --pets 10000 --profile mixed --seed 1 --format json
+//
+// Options:
+//   --pets         number of pets to simulate            (default 10000)
+//   --profile      diligent | casual | absentee | mixed  (default mixed)
+//   --seed         RNG seed                               (default 1)
+//   --max-days     simulation horizon in days             (default 400)
+//   --crank-blocks blocks between UpdateState calls       (default 144)
+//   --supply       circulating $GOTCHI, raw units         (default 150M tokens)
+//   --format       json | csv                             (default json)
+
+use serde::Serialize;
+
+use satsgotchi::{Level, Status, SPECIES};
+use satsgotchi_simulator::{
+    percent, to_csv, Args, OutputFormat, OwnerProfile, PetSim, Result, SimError, SimRng,
+    BLOCKS_PER_DAY, BLOCKS_PER_HOUR, LAUNCH_SUPPLY, RARITY_NAMES, START_BLOCK,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+const LEVELS: [Level; 6] = [
+    Level::Baby,
+    Level::Child,
+    Level::Teen,
+    Level::Adult,
+    Level::Senior,
+    Level::Ascended,
+];
+
+// ============================================================================
+// SIMULATION
+// ============================================================================
+
+#[derive(Debug, Clone)]
+struct SimConfig {
+    pets: u64,
+    profile: String,
+    seed: u64,
+    max_days: u64,
+    crank_blocks: u64,
+    supply: u64,
+    format: OutputFormat,
+}
+
+/// Final state of one simulated pet
+#[derive(Debug, Clone)]
+struct PetOutcome {
+    profile: OwnerProfile,
+    species: &'static str,
+    rarity: u8,
+    highest_level: Level,
+    died_at_block: Option<u64>,
+    total_earned: u64,
+}
+
+/// Simulate a single pet from birth until death, ascension or the horizon
+fn simulate_pet(config: &SimConfig, profile: OwnerProfile, index: u64, rng: &mut SimRng) -> PetOutcome {
+    let mut pet = PetSim::new(profile, index, START_BLOCK);
+    let end_block = START_BLOCK + config.max_days * BLOCKS_PER_DAY;
+    let mut block = START_BLOCK;
+
+    while block < end_block && pet.is_active() {
+        block += BLOCKS_PER_HOUR;
+        pet.step_hour(block, config.crank_blocks, config.supply, rng);
+    }
+
+    PetOutcome {
+        profile,
+        species: pet.state.traits.species().name,
+        rarity: pet.state.traits.rarity,
+        highest_level: pet.state.level.clone(),
+        died_at_block: match pet.state.status {
+            Status::Dead => Some(pet.state.died_block),
+            Status::Alive => None,
+        },
+        total_earned: pet.state.total_earned,
+    }
+}
+
+// ============================================================================
+// REPORTING
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+struct LevelReport {
+    level: String,
+    reached: u64,
+    died: u64,
+    death_rate_pct: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct GroupReport {
+    /// "all", an owner profile, "species:<name>" or "rarity:<tier>"
+    group: String,
+    pets: u64,
+    died: u64,
+    death_rate_pct: f64,
+    ascended: u64,
+    ascension_rate_pct: f64,
+    still_alive: u64,
+    mean_days_to_death: f64,
+    median_days_to_death: f64,
+    /// Raw `total_earned` as recorded by the program, summed over the group
+    total_gotchi_earned: u64,
+    levels: Vec<LevelReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SimulationReport {
+    pets: u64,
+    profile: String,
+    seed: u64,
+    max_days: u64,
+    crank_blocks: u64,
+    groups: Vec<GroupReport>,
+    by_species: Vec<GroupReport>,
+    by_rarity: Vec<GroupReport>,
+}
+
+fn level_index(level: &Level) -> usize {
+    LEVELS.iter().position(|l| l == level).unwrap_or(0)
+}
+
+fn summarize(name: &str, outcomes: &[&PetOutcome]) -> GroupReport {
+    let pets = outcomes.len() as u64;
+
+    let mut days_to_death: Vec<f64> = outcomes
+        .iter()
+        .filter_map(|o| o.died_at_block)
+        .map(|b| (b - START_BLOCK) as f64 / BLOCKS_PER_DAY as f64)
+        .collect();
+    days_to_death.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let died = days_to_death.len() as u64;
+    let ascended = outcomes
+        .iter()
+        .filter(|o| o.highest_level == Level::Ascended)
+        .count() as u64;
+
+    let mean_days_to_death = if died == 0 {
+        0.0
+    } else {
+        days_to_death.iter().sum::<f64>() / died as f64
+    };
+    let median_days_to_death = days_to_death
+        .get(days_to_death.len() / 2)
+        .copied()
+        .unwrap_or(0.0);
+
+    // Deaths per level, relative to the pets that reached that level
+    let levels = LEVELS[..5]
+        .iter()
+        .map(|level| {
+            let idx = level_index(level);
+            let reached = outcomes
+                .iter()
+                .filter(|o| level_index(&o.highest_level) >= idx)
+                .count() as u64;
+            let died_here = outcomes
+                .iter()
+                .filter(|o| o.died_at_block.is_some() && level_index(&o.highest_level) == idx)
+                .count() as u64;
+            LevelReport {
+                level: format!("{:?}", level),
+                reached,
+                died: died_here,
+                death_rate_pct: percent(died_here, reached),
+            }
+        })
+        .collect();
+
+    GroupReport {
+        group: name.to_string(),
+        pets,
+        died,
+        death_rate_pct: percent(died, pets),
+        ascended,
+        ascension_rate_pct: percent(ascended, pets),
+        still_alive: pets - died - ascended,
+        mean_days_to_death,
+        median_days_to_death,
+        total_gotchi_earned: outcomes.iter().map(|o| o.total_earned).sum(),
+        levels,
+    }
+}
+
+fn render_csv(report: &SimulationReport) -> String {
+    let mut header = vec![
+        "group",
+        "pets",
+        "died",
+        "death_rate_pct",
+        "ascended",
+        "ascension_rate_pct",
+        "still_alive",
+        "mean_days_to_death",
+        "median_days_to_death",
+        "total_gotchi_earned",
+    ];
+    let level_columns: Vec<String> = LEVELS[..5]
+        .iter()
+        .map(|l| format!("death_rate_pct_{:?}", l).to_lowercase())
+        .collect();
+    header.extend(level_columns.iter().map(|s| s.as_str()));
+
+    let rows: Vec<Vec<String>> = report
+        .groups
+        .iter()
+        .chain(&report.by_species)
+        .chain(&report.by_rarity)
+        .map(|g| {
+            let mut row = vec![
+                g.group.clone(),
+                g.pets.to_string(),
+                g.died.to_string(),
+                format!("{:.4}", g.death_rate_pct),
+                g.ascended.to_string(),
+                format!("{:.4}", g.ascension_rate_pct),
+                g.still_alive.to_string(),
+                format!("{:.2}", g.mean_days_to_death),
+                format!("{:.2}", g.median_days_to_death),
+                g.total_gotchi_earned.to_string(),
+            ];
+            row.extend(g.levels.iter().map(|l| format!("{:.4}", l.death_rate_pct)));
+            row
+        })
+        .collect();
+
+    to_csv(&header, &rows)
+}
+
+// ============================================================================
+// MAIN ENTRY POINT
+// ============================================================================
+
+fn run(config: SimConfig) -> Result<SimulationReport> {
+    if config.crank_blocks == 0 || !config.crank_blocks.is_multiple_of(BLOCKS_PER_HOUR) {
+        return Err(SimError::InvalidArgument(
+            "--crank-blocks must be a non-zero multiple of 6".to_string(),
+        ));
+    }
+
+    let mut rng = SimRng::new(config.seed);
+    let mut outcomes = Vec::with_capacity(config.pets as usize);
+
+    for index in 0..config.pets {
+        let profile = OwnerProfile::pick(&config.profile, &mut rng)?;
+        outcomes.push(simulate_pet(&config, profile, index, &mut rng));
+    }
+
+    let mut groups = vec![summarize("all", &outcomes.iter().collect::<Vec<_>>())];
+    for profile in OwnerProfile::ALL {
+        let members: Vec<&PetOutcome> = outcomes.iter().filter(|o| o.profile == profile).collect();
+        if !members.is_empty() {
+            groups.push(summarize(profile.name(), &members));
+        }
+    }
+
+    // Species and rarity change decay, hunger and earnings, so break them out too
+    let by_species = SPECIES
+        .iter()
+        .map(|species| {
+            let members: Vec<&PetOutcome> = outcomes.iter().filter(|o| o.species == species.name).collect();
+            summarize(&format!("species:{}", species.name), &members)
+        })
+        .filter(|group| group.pets > 0)
+        .collect();
+    let by_rarity = RARITY_NAMES
+        .iter()
+        .enumerate()
+        .map(|(rarity, name)| {
+            let members: Vec<&PetOutcome> = outcomes.iter().filter(|o| o.rarity as usize == rarity).collect();
+            summarize(&format!("rarity:{}", name), &members)
+        })
+        .filter(|group| group.pets > 0)
+        .collect();
+
+    Ok(SimulationReport {
+        pets: config.pets,
+        profile: config.profile,
+        seed: config.seed,
+        max_days: config.max_days,
+        crank_blocks: config.crank_blocks,
+        groups,
+        by_species,
+        by_rarity,
+    })
+}
+
+fn main() -> Result<()> {
+    let args = Args::from_env()?;
+    let config = SimConfig {
+        pets: args.get("pets", 10_000)?,
+        profile: args.get("profile", "mixed".to_string())?,
+        seed: args.get("seed", 1)?,
+        max_days: args.get("max-days", 400)?,
+        crank_blocks: args.get("crank-blocks", BLOCKS_PER_DAY)?,
+        supply: args.get("supply", LAUNCH_SUPPLY)?,
+        format: args.get("format", OutputFormat::Json)?,
+    };
+    let format = config.format;
+
+    let report = run(config)?;
+
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        OutputFormat::Csv => print!("{}", render_csv(&report)),
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(profile: &str, pets: u64) -> SimConfig {
+        SimConfig {
+            pets,
+            profile: profile.to_string(),
+            seed: 7,
+            max_days: 400,
+            crank_blocks: BLOCKS_PER_DAY,
+            supply: LAUNCH_SUPPLY,
+            format: OutputFormat::Json,
+        }
+    }
+
+    #[test]
+    fn test_absentee_owners_lose_more_pets() {
+        let absentee = run(config("absentee", 200)).unwrap();
+        let diligent = run(config("diligent", 200)).unwrap();
+
+        assert!(absentee.groups[0].death_rate_pct > diligent.groups[0].death_rate_pct);
+    }
+
+    #[test]
+    fn test_report_is_consistent() {
+        let report = run(config("mixed", 300)).unwrap();
+        let all = &report.groups[0];
+
+        assert_eq!(all.died + all.ascended + all.still_alive, all.pets);
+        assert_eq!(all.levels[0].reached, all.pets);
+        assert_eq!(
+            report.groups[1..].iter().map(|g| g.pets).sum::<u64>(),
+            all.pets
+        );
+        assert_eq!(report.by_species.iter().map(|g| g.pets).sum::<u64>(), all.pets);
+        assert_eq!(report.by_rarity.iter().map(|g| g.pets).sum::<u64>(), all.pets);
+        assert!(report.by_species.len() > 1);
+    }
+
+    #[test]
+    fn test_rejects_bad_crank_interval() {
+        let mut bad = config("casual", 1);
+        bad.crank_blocks = 7;
+        assert!(run(bad).is_err());
+    }
+}
@@ -0,0 +1,583 @@
+// SATSGOTCHI TOKENOMICS SIMULATOR
+// Monte Carlo run of the $GOTCHI economy: a growing player base cares for
+// pets through the real game engine while the real token engine handles
+// burns, milestone and earning mints, trading fees and buyback & burn
+//
+// Usage:
+//   tokenomics-sim --days 730 --initial-players 1000 --growth logistic --format csv
+//
+// Options:
+//   --days            simulation horizon in days                      (default 730)
+//   --initial-players pets minted on day 0                            (default 1000)
+//   --growth          linear | exponential | logistic                 (default logistic)
+//   --growth-bps      daily growth rate in basis points               (default 100)
+//   --max-players     cap on total pets ever minted                   (default 20000)
+//   --profile         diligent | casual | absentee | mixed            (default mixed)
+//   --initial-supply  circulating supply at launch, raw units         (default 150M tokens)
+//   --sell-bps        share of claimed earnings players sell          (default 5000)
+//   --volume-bps      daily organic trading volume vs. circulating    (default 100)
+//   --buyback-days    days between BuybackAndBurn runs                (default 7)
+//   --report-days     days between report rows                        (default 7)
+//   --crank-blocks    blocks between UpdateState calls                (default 144)
+//   --seed            RNG seed                                        (default 1)
+//   --format          json | csv                                      (default json)
+
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use gotchi_token::{
+    apply_burn, apply_buyback_and_burn, apply_mint_earning, apply_mint_milestone, apply_transfer,
+    arch_program::{program_error::ProgramError, pubkey::Pubkey},
+    calculate_dynamic_burn, simulate_buyback, AccountBalance, MilestoneType, TokenState,
+    BUY_FEE_BPS, EARNING_POOL, MILESTONE_POOL,
+};
+use satsgotchi::{Level, Status};
+use satsgotchi_simulator::{
+    to_csv, Args, HourActions, OutputFormat, OwnerProfile, PetSim, Result, SimError, SimRng,
+    BLOCKS_PER_DAY, BLOCKS_PER_HOUR, LAUNCH_SUPPLY, START_BLOCK,
+};
+
+// ============================================================================
+// CONFIGURATION
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum GrowthCurve {
+    Linear,
+    Exponential,
+    Logistic,
+}
+
+impl FromStr for GrowthCurve {
+    type Err = SimError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "linear" => Ok(GrowthCurve::Linear),
+            "exponential" => Ok(GrowthCurve::Exponential),
+            "logistic" => Ok(GrowthCurve::Logistic),
+            other => Err(SimError::InvalidArgument(format!("unknown growth curve: {}", other))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SimConfig {
+    days: u64,
+    initial_players: u64,
+    growth: GrowthCurve,
+    growth_bps: u64,
+    max_players: u64,
+    profile: String,
+    initial_supply: u64,
+    sell_bps: u64,
+    volume_bps: u64,
+    buyback_days: u64,
+    report_days: u64,
+    crank_blocks: u64,
+    seed: u64,
+    #[serde(skip)]
+    format: OutputFormat,
+}
+
+impl SimConfig {
+    /// Total pets minted by the end of `day` under the configured growth curve
+    fn players_by_day(&self, day: u64) -> u64 {
+        let n0 = self.initial_players as f64;
+        let rate = self.growth_bps as f64 / 10_000.0;
+        let cap = self.max_players as f64;
+        let t = day as f64;
+
+        let total = match self.growth {
+            GrowthCurve::Linear => n0 + n0 * rate * t,
+            GrowthCurve::Exponential => n0 * (1.0 + rate).powf(t),
+            GrowthCurve::Logistic => {
+                if n0 <= 0.0 {
+                    0.0
+                } else {
+                    cap / (1.0 + ((cap - n0) / n0) * (-rate * t).exp())
+                }
+            }
+        };
+
+        (total as u64).min(self.max_players)
+    }
+}
+
+// ============================================================================
+// ECONOMY
+// ============================================================================
+
+/// Token-side state of the simulated world
+struct Economy {
+    token: TokenState,
+    /// Liquidity pool / other holders players trade against
+    market: AccountBalance,
+    /// All players' wallets, pooled
+    players: AccountBalance,
+    /// Fee collection wallet feeding BuybackAndBurn
+    fees: AccountBalance,
+    minted: u64,
+    burned: u64,
+    unfunded_burns: u64,
+    milestone_depleted: Option<Depletion>,
+    earning_depleted: Option<Depletion>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+struct Depletion {
+    day: u64,
+    block: u64,
+}
+
+impl Economy {
+    fn new(initial_supply: u64) -> Self {
+        let authority = Pubkey::default();
+        let balance = |amount| AccountBalance {
+            owner: authority,
+            balance: amount,
+        };
+
+        Self {
+            token: TokenState::new(
+                "Satsgotchi Token".to_string(),
+                "GOTCHI".to_string(),
+                9,
+                initial_supply,
+                authority,
+                authority,
+            ),
+            market: balance(initial_supply),
+            players: balance(0),
+            fees: balance(0),
+            minted: 0,
+            burned: 0,
+            unfunded_burns: 0,
+            milestone_depleted: None,
+            earning_depleted: None,
+        }
+    }
+
+    /// Players buy `amount` (gross) from the market, capped by market depth
+    fn buy(&mut self, amount: u64) {
+        let amount = amount.min(self.market.balance);
+        if amount > 0 {
+            let _ = apply_transfer(
+                &mut self.token,
+                &mut self.market,
+                &mut self.players,
+                &mut self.fees,
+                amount,
+                true,
+            );
+        }
+    }
+
+    /// Players sell `amount` back to the market, capped by their balance
+    fn sell(&mut self, amount: u64) {
+        let amount = amount.min(self.players.balance);
+        if amount > 0 {
+            let _ = apply_transfer(
+                &mut self.token,
+                &mut self.players,
+                &mut self.market,
+                &mut self.fees,
+                amount,
+                false,
+            );
+        }
+    }
+
+    /// Burn the dynamic cost of a care action, buying tokens first if needed
+    fn burn_for(&mut self, action: &str) {
+        let cost = calculate_dynamic_burn(action, self.token.circulating_supply);
+        if cost == 0 {
+            return;
+        }
+
+        if self.players.balance < cost {
+            // Gross up so the buy fee doesn't leave the wallet short
+            let shortfall = (cost - self.players.balance) as u128;
+            let gross = shortfall * 10_000 / (10_000 - BUY_FEE_BPS as u128) + 1;
+            self.buy(gross as u64);
+        }
+
+        match apply_burn(&mut self.token, &mut self.players, cost) {
+            Ok(()) => self.burned += cost,
+            Err(_) => self.unfunded_burns += 1,
+        }
+    }
+
+    fn mint_milestone(&mut self, from: &Level, day: u64, block: u64) {
+        let milestone_type = match from {
+            Level::Baby => MilestoneType::BabyToChild,
+            Level::Child => MilestoneType::ChildToTeen,
+            Level::Teen => MilestoneType::TeenToAdult,
+            Level::Adult => MilestoneType::AdultToSenior,
+            Level::Senior => MilestoneType::SeniorToAscension,
+            _ => return,
+        };
+
+        if let Ok(amount) = apply_mint_milestone(&mut self.token, &mut self.players, milestone_type) {
+            self.minted += amount;
+        }
+        if self.token.milestone_used >= MILESTONE_POOL && self.milestone_depleted.is_none() {
+            self.milestone_depleted = Some(Depletion { day, block });
+        }
+    }
+
+    /// Mint a pet's claim; returns false once the earning pool can't cover it
+    fn mint_earning(&mut self, amount: u64, day: u64, block: u64) -> bool {
+        match apply_mint_earning(&mut self.token, &mut self.players, amount) {
+            Ok(()) => {
+                self.minted += amount;
+                true
+            }
+            Err(ProgramError::Custom(3)) => {
+                if self.earning_depleted.is_none() {
+                    self.earning_depleted = Some(Depletion { day, block });
+                }
+                false
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn buyback(&mut self) {
+        let tokens_per_sat = simulate_buyback(1, self.token.circulating_supply);
+        if tokens_per_sat == 0 {
+            return;
+        }
+        let btc_amount = self.fees.balance / tokens_per_sat;
+        if btc_amount == 0 {
+            return;
+        }
+        if let Ok(burned) = apply_buyback_and_burn(&mut self.token, &mut self.fees, btc_amount) {
+            self.burned += burned;
+        }
+    }
+
+    fn charge(&mut self, actions: &HourActions, day: u64, block: u64) {
+        if actions.fed {
+            self.burn_for("feed_meal");
+        }
+        if actions.played {
+            self.burn_for("play_game");
+        }
+        if actions.cleaned {
+            self.burn_for("clean");
+        }
+        if actions.medicine {
+            self.burn_for("medicine");
+        }
+        if let Some(level) = &actions.evolved_from {
+            self.mint_milestone(level, day, block);
+        }
+    }
+}
+
+// ============================================================================
+// REPORTING
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+struct Snapshot {
+    day: u64,
+    block: u64,
+    players_joined: u64,
+    pets_alive: u64,
+    circulating_supply: u64,
+    total_supply: u64,
+    total_burned: u64,
+    total_fees_collected: u64,
+    milestone_used: u64,
+    milestone_remaining: u64,
+    earning_used: u64,
+    earning_remaining: u64,
+    minted_in_period: u64,
+    burned_in_period: u64,
+    /// Burned / minted over the period (None when nothing was minted)
+    burn_mint_ratio: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SimulationReport {
+    config: SimConfig,
+    milestone_pool_depleted: Option<Depletion>,
+    earning_pool_depleted: Option<Depletion>,
+    lifetime_minted: u64,
+    lifetime_burned: u64,
+    lifetime_burn_mint_ratio: Option<f64>,
+    unfunded_burns: u64,
+    timeline: Vec<Snapshot>,
+}
+
+fn ratio(burned: u64, minted: u64) -> Option<f64> {
+    if minted == 0 {
+        None
+    } else {
+        Some(burned as f64 / minted as f64)
+    }
+}
+
+fn snapshot(
+    economy: &Economy,
+    day: u64,
+    block: u64,
+    players_joined: u64,
+    pets_alive: u64,
+    minted_in_period: u64,
+    burned_in_period: u64,
+) -> Snapshot {
+    let token = &economy.token;
+    Snapshot {
+        day,
+        block,
+        players_joined,
+        pets_alive,
+        circulating_supply: token.circulating_supply,
+        total_supply: token.total_supply,
+        total_burned: token.total_burned,
+        total_fees_collected: token.total_fees_collected,
+        milestone_used: token.milestone_used,
+        milestone_remaining: MILESTONE_POOL - token.milestone_used,
+        earning_used: token.earning_used,
+        earning_remaining: EARNING_POOL - token.earning_used,
+        minted_in_period,
+        burned_in_period,
+        burn_mint_ratio: ratio(burned_in_period, minted_in_period),
+    }
+}
+
+fn render_csv(report: &SimulationReport) -> String {
+    let header = [
+        "day",
+        "block",
+        "players_joined",
+        "pets_alive",
+        "circulating_supply",
+        "total_supply",
+        "total_burned",
+        "total_fees_collected",
+        "milestone_used",
+        "milestone_remaining",
+        "earning_used",
+        "earning_remaining",
+        "minted_in_period",
+        "burned_in_period",
+        "burn_mint_ratio",
+    ];
+    let rows: Vec<Vec<String>> = report
+        .timeline
+        .iter()
+        .map(|s| {
+            vec![
+                s.day.to_string(),
+                s.block.to_string(),
+                s.players_joined.to_string(),
+                s.pets_alive.to_string(),
+                s.circulating_supply.to_string(),
+                s.total_supply.to_string(),
+                s.total_burned.to_string(),
+                s.total_fees_collected.to_string(),
+                s.milestone_used.to_string(),
+                s.milestone_remaining.to_string(),
+                s.earning_used.to_string(),
+                s.earning_remaining.to_string(),
+                s.minted_in_period.to_string(),
+                s.burned_in_period.to_string(),
+                s.burn_mint_ratio.map(|r| format!("{:.6}", r)).unwrap_or_default(),
+            ]
+        })
+        .collect();
+
+    to_csv(&header, &rows)
+}
+
+// ============================================================================
+// MAIN ENTRY POINT
+// ============================================================================
+
+fn run(config: SimConfig) -> Result<SimulationReport> {
+    if config.crank_blocks == 0 || !config.crank_blocks.is_multiple_of(BLOCKS_PER_HOUR) {
+        return Err(SimError::InvalidArgument(
+            "--crank-blocks must be a non-zero multiple of 6".to_string(),
+        ));
+    }
+    if config.buyback_days == 0 || config.report_days == 0 {
+        return Err(SimError::InvalidArgument(
+            "--buyback-days and --report-days must be non-zero".to_string(),
+        ));
+    }
+
+    let mut rng = SimRng::new(config.seed);
+    let mut economy = Economy::new(config.initial_supply);
+    let mut pets: Vec<PetSim> = Vec::new();
+    let mut players_joined = 0u64;
+    let mut block = START_BLOCK;
+    let mut timeline = Vec::new();
+    let (mut period_minted, mut period_burned) = (0u64, 0u64);
+
+    for day in 0..config.days {
+        let (minted_before, burned_before) = (economy.minted, economy.burned);
+
+        // New players join and mint a pet at the start of the day
+        let target = config.players_by_day(day);
+        while players_joined < target {
+            let profile = OwnerProfile::pick(&config.profile, &mut rng)?;
+            pets.push(PetSim::new(profile, players_joined, block));
+            players_joined += 1;
+        }
+
+        // Hourly care and cranks (ascended pets keep ticking and earning)
+        for _ in 0..BLOCKS_PER_DAY / BLOCKS_PER_HOUR {
+            block += BLOCKS_PER_HOUR;
+            for pet in pets.iter_mut() {
+                if pet.state.status == Status::Dead {
+                    continue;
+                }
+                let supply = economy.token.circulating_supply;
+                let actions = pet.step_hour(block, config.crank_blocks, supply, &mut rng);
+                economy.charge(&actions, day, block);
+            }
+        }
+
+        // Owners claim their earnings once a day and sell part of them
+        let claimed_before = economy.minted;
+        for pet in pets.iter_mut() {
+            let amount = pet.state.unclaimed_rewards;
+            if amount > 0 && economy.mint_earning(amount, day, block) {
+                pet.state.unclaimed_rewards = 0;
+            }
+        }
+        let claimed = economy.minted - claimed_before;
+        economy.sell((claimed as u128 * config.sell_bps as u128 / 10_000) as u64);
+
+        // Organic trading volume, split evenly between buys and sells
+        let volume =
+            (economy.token.circulating_supply as u128 * config.volume_bps as u128 / 10_000) as u64;
+        economy.buy(volume / 2);
+        economy.sell(volume / 2);
+
+        if (day + 1) % config.buyback_days == 0 {
+            economy.buyback();
+        }
+
+        pets.retain(|p| p.state.status == Status::Alive);
+
+        period_minted += economy.minted - minted_before;
+        period_burned += economy.burned - burned_before;
+        if (day + 1) % config.report_days == 0 || day + 1 == config.days {
+            timeline.push(snapshot(
+                &economy,
+                day + 1,
+                block,
+                players_joined,
+                pets.len() as u64,
+                period_minted,
+                period_burned,
+            ));
+            period_minted = 0;
+            period_burned = 0;
+        }
+    }
+
+    Ok(SimulationReport {
+        config,
+        milestone_pool_depleted: economy.milestone_depleted,
+        earning_pool_depleted: economy.earning_depleted,
+        lifetime_minted: economy.minted,
+        lifetime_burned: economy.burned,
+        lifetime_burn_mint_ratio: ratio(economy.burned, economy.minted),
+        unfunded_burns: economy.unfunded_burns,
+        timeline,
+    })
+}
+
+fn main() -> Result<()> {
+    let args = Args::from_env()?;
+    let config = SimConfig {
+        days: args.get("days", 730)?,
+        initial_players: args.get("initial-players", 1_000)?,
+        growth: args.get("growth", GrowthCurve::Logistic)?,
+        growth_bps: args.get("growth-bps", 100)?,
+        max_players: args.get("max-players", 20_000)?,
+        profile: args.get("profile", "mixed".to_string())?,
+        initial_supply: args.get("initial-supply", LAUNCH_SUPPLY)?,
+        sell_bps: args.get("sell-bps", 5_000)?,
+        volume_bps: args.get("volume-bps", 100)?,
+        buyback_days: args.get("buyback-days", 7)?,
+        report_days: args.get("report-days", 7)?,
+        crank_blocks: args.get("crank-blocks", BLOCKS_PER_DAY)?,
+        seed: args.get("seed", 1)?,
+        format: args.get("format", OutputFormat::Json)?,
+    };
+    let format = config.format;
+
+    let report = run(config)?;
+
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        OutputFormat::Csv => print!("{}", render_csv(&report)),
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(days: u64, players: u64) -> SimConfig {
+        SimConfig {
+            days,
+            initial_players: players,
+            growth: GrowthCurve::Logistic,
+            growth_bps: 100,
+            max_players: players * 2,
+            profile: "mixed".to_string(),
+            initial_supply: LAUNCH_SUPPLY,
+            sell_bps: 5_000,
+            volume_bps: 100,
+            buyback_days: 7,
+            report_days: 7,
+            crank_blocks: BLOCKS_PER_DAY,
+            seed: 11,
+            format: OutputFormat::Json,
+        }
+    }
+
+    #[test]
+    fn test_growth_curves() {
+        let mut cfg = config(10, 100);
+        cfg.max_players = 1_000;
+
+        cfg.growth = GrowthCurve::Linear;
+        assert_eq!(cfg.players_by_day(0), 100);
+        assert_eq!(cfg.players_by_day(10), 110);
+
+        cfg.growth = GrowthCurve::Logistic;
+        assert_eq!(cfg.players_by_day(0), 100);
+        assert!(cfg.players_by_day(10_000) <= 1_000);
+    }
+
+    #[test]
+    fn test_supply_accounting_balances() {
+        let report = run(config(60, 50)).unwrap();
+        let last = report.timeline.last().unwrap();
+
+        assert_eq!(
+            last.circulating_supply,
+            LAUNCH_SUPPLY + report.lifetime_minted - report.lifetime_burned
+        );
+        assert_eq!(last.total_burned, report.lifetime_burned);
+        assert!(last.milestone_used > 0);
+        assert!(last.total_fees_collected > 0);
+    }
+}